@@ -0,0 +1,53 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use control_flow::{sum_with_for, sum_with_iterator, sum_with_loop, sum_with_while, Skip};
+
+// 12! is the biggest factorial that fits in a u32, and 8 copies of it the biggest sum.
+const NUMS: [u32; 3] = [3, 8, 12];
+
+fn factorial(c: &mut Criterion) {
+    let mut group = c.benchmark_group("factorial");
+
+    for num in NUMS {
+        group.bench_with_input(BenchmarkId::new("loop", num), &num, |b, &num| {
+            b.iter(|| control_flow::factorial(black_box(num)))
+        });
+        // Checked like `factorial`, so only the loop style differs.
+        group.bench_with_input(BenchmarkId::new("try_fold", num), &num, |b, &num| {
+            b.iter(|| (1..=black_box(num)).try_fold(1u32, u32::checked_mul))
+        });
+        // Unchecked, to show what the overflow checks themselves cost.
+        group.bench_with_input(BenchmarkId::new("product", num), &num, |b, &num| {
+            b.iter(|| (1..=black_box(num)).product::<u32>())
+        });
+    }
+
+    group.finish();
+}
+
+fn sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum");
+
+    for num in NUMS {
+        for count in [1, 4, 8] {
+            let input = format!("{num}x{count}");
+            group.bench_with_input(BenchmarkId::new("loop", &input), &(num, count), |b, &(num, count)| {
+                b.iter(|| sum_with_loop(black_box(num), black_box(count), Skip::Never))
+            });
+            group.bench_with_input(BenchmarkId::new("while", &input), &(num, count), |b, &(num, count)| {
+                b.iter(|| sum_with_while(black_box(num), black_box(count), Skip::Never))
+            });
+            group.bench_with_input(BenchmarkId::new("for", &input), &(num, count), |b, &(num, count)| {
+                b.iter(|| sum_with_for(black_box(num), black_box(count), Skip::Never))
+            });
+            group.bench_with_input(BenchmarkId::new("iterator", &input), &(num, count), |b, &(num, count)| {
+                b.iter(|| sum_with_iterator(black_box(num), black_box(count), Skip::Never))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, factorial, sum);
+criterion_main!(benches);
@@ -0,0 +1,155 @@
+use crate::Skip;
+
+pub const USAGE: &str = "Usage: control_flow [--num <number>] [--count <number>] [--skip <mode>] [--threads <number> | --rayon] [--format <human|json>]
+       control_flow <exercise> [value]
+
+Exercises:
+    fizzbuzz <number>           FizzBuzz from 1 up to the number
+    fibonacci <n>               The nth Fibonacci number
+    to-celsius <degrees>        Convert Fahrenheit to Celsius
+    to-fahrenheit <degrees>     Convert Celsius to Fahrenheit
+    big-factorial <n>           The exact n!, for numbers too big for a u32
+    christmas                   The lyrics of \"The Twelve Days of Christmas\"
+
+Options, all but --help only apply to the factorial sum:
+    --num <number>       Number to take the factorial of, defaults to 10
+    --count <number>     How many times to add the factorial to the result, defaults to 4
+    --skip <mode>        Leave out odd or even counts with `continue 'counting_up`, defaults to never
+    --threads <number>   Split the counts across this many threads
+    --rayon              Split the counts with rayon, needs the `rayon` cargo feature
+    --format <format>    human (default) or json
+    -h, --help           Print this help";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Human,
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Command {
+    #[default]
+    Factorial,
+    FizzBuzz(u32),
+    Fibonacci(u32),
+    ToCelsius(f64),
+    ToFahrenheit(f64),
+    Christmas,
+    BigFactorial(u32),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    #[default]
+    Sequential,
+    Threads(usize),
+    Rayon,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub command: Command,
+    pub num: u32,
+    pub count: u32,
+    pub skip: Skip,
+    pub mode: Mode,
+    pub format: Format,
+    pub help: bool,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            command: Command::default(),
+            num: 10,
+            count: 4,
+            skip: Skip::default(),
+            mode: Mode::default(),
+            format: Format::default(),
+            help: false,
+        }
+    }
+}
+
+pub fn parse_args<I>(args: I) -> Result<Options, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut options = Options::default();
+    let mut args = args.into_iter().peekable();
+
+    let exercise = match args.peek().map(String::as_str) {
+        Some("fizzbuzz" | "fibonacci" | "to-celsius" | "to-fahrenheit" | "christmas" | "big-factorial") => args.next(),
+        _ => None,
+    };
+
+    if let Some(exercise) = &exercise {
+        if exercise == "christmas" {
+            options.command = Command::Christmas;
+        } else {
+            let value = args.next().ok_or(format!("{} needs a value", exercise))?;
+            let invalid = || format!("'{}' is not a valid value for {}", value, exercise);
+            options.command = match exercise.as_str() {
+                "fizzbuzz" => Command::FizzBuzz(value.parse().map_err(|_| invalid())?),
+                "fibonacci" => Command::Fibonacci(value.parse().map_err(|_| invalid())?),
+                "to-celsius" => Command::ToCelsius(value.parse().map_err(|_| invalid())?),
+                "big-factorial" => Command::BigFactorial(value.parse().map_err(|_| invalid())?),
+                _ => Command::ToFahrenheit(value.parse().map_err(|_| invalid())?),
+            };
+        }
+    }
+
+    while let Some(arg) = args.next() {
+        let factorial_only = matches!(arg.as_str(), "--num" | "--count" | "--skip" | "--threads" | "--rayon" | "--format");
+        if let (true, Some(exercise)) = (factorial_only, &exercise) {
+            return Err(format!("{} only applies to the factorial sum, not to {}", arg, exercise));
+        }
+
+        match arg.as_str() {
+            "--num" => {
+                let value = args.next().ok_or("--num needs a value")?;
+                options.num = match value.parse() {
+                    Ok(0) | Err(_) => return Err(format!("'{}' is not a positive number", value)),
+                    Ok(num) => num,
+                };
+            },
+            "--count" => {
+                let value = args.next().ok_or("--count needs a value")?;
+                options.count = value
+                    .parse()
+                    .map_err(|_| format!("'{}' is not a valid count", value))?;
+            },
+            "--skip" => {
+                let value = args.next().ok_or("--skip needs a value")?;
+                options.skip = value.parse()?;
+            },
+            "--threads" => {
+                let value = args.next().ok_or("--threads needs a value")?;
+                let threads = match value.parse() {
+                    Ok(0) | Err(_) => return Err(format!("'{}' is not a valid number of threads", value)),
+                    Ok(threads) => threads,
+                };
+                options.mode = Mode::Threads(threads);
+            },
+            "--rayon" => {
+                if !cfg!(feature = "rayon") {
+                    return Err(String::from("--rayon needs control_flow to be built with `--features rayon`"));
+                }
+                options.mode = Mode::Rayon;
+            },
+            "--format" => {
+                let value = args.next().ok_or("--format needs a value")?;
+                options.format = match value.as_str() {
+                    "human" => Format::Human,
+                    "json" => Format::Json,
+                    _ => return Err(format!("'{}' is not a format, use human or json", value)),
+                };
+            },
+            "-h" | "--help" => options.help = true,
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    Ok(options)
+}
@@ -0,0 +1,176 @@
+use std::str::FromStr;
+
+use crate::FactorialError;
+
+/// Which counts to leave out of the result.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Skip {
+    #[default]
+    Never,
+    Odd,
+    Even,
+}
+
+impl Skip {
+    pub fn matches(&self, count: u32) -> bool {
+        match self {
+            Skip::Never => false,
+            Skip::Odd => !count.is_multiple_of(2),
+            Skip::Even => count.is_multiple_of(2),
+        }
+    }
+}
+
+impl FromStr for Skip {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "never" => Ok(Skip::Never),
+            "odd" => Ok(Skip::Odd),
+            "even" => Ok(Skip::Even),
+            _ => Err(format!("'{}' is not a skip mode, use never, odd or even", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Iteration {
+    pub count: u32,
+    pub factorial: u32,
+}
+
+/// `num!` added up `count` times, with the factorial of every iteration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub iterations: Vec<Iteration>,
+    /// Counts left out because of the `Skip` mode.
+    pub skipped: Vec<u32>,
+    pub result: u32,
+}
+
+impl Summary {
+    /// The `--format json` output, one line.
+    pub fn to_json(&self, num: u32, count: u32) -> String {
+        let iterations: Vec<String> = self
+            .iterations
+            .iter()
+            .map(|iteration| format!("{{\"count\": {}, \"factorial\": {}}}", iteration.count, iteration.factorial))
+            .collect();
+        let skipped: Vec<String> = self.skipped.iter().map(|count| count.to_string()).collect();
+
+        format!(
+            "{{\"num\": {}, \"count\": {}, \"iterations\": [{}], \"skipped\": [{}], \"result\": {}}}",
+            num,
+            count,
+            iterations.join(", "),
+            skipped.join(", "),
+            self.result
+        )
+    }
+}
+
+/// The README version, a labeled `loop` with a nested `loop` that breaks with a value.
+/// Skipped counts leave the nested loop early with `continue 'counting_up`, and
+/// `break 'counting_up` ends the outer loop once the count reaches zero.
+pub fn sum_with_loop(num: u32, count: u32, skip: Skip) -> Result<Summary, FactorialError> {
+    let mut iterations = Vec::new();
+    let mut skipped = Vec::new();
+    let mut count = count;
+    let mut result: u32 = 0;
+
+    'counting_up: loop {
+        if count == 0 {
+            break 'counting_up;
+        }
+        let mut current = num;
+        let mut factorial: u32 = 1;
+        let value = loop {
+            if skip.matches(count) {
+                skipped.push(count);
+                count -= 1;
+                continue 'counting_up;
+            }
+            if current <= 1 {
+                break factorial;
+            }
+            factorial = factorial.checked_mul(current).ok_or(FactorialError::Overflow { n: num })?;
+            current -= 1;
+        };
+        result = result.checked_add(value).ok_or(FactorialError::SumOverflow)?;
+        iterations.push(Iteration { count, factorial });
+        count -= 1;
+    }
+
+    Ok(Summary { iterations, skipped, result })
+}
+
+/// The same computation with `while` loops, the conditions move into the loop headers.
+pub fn sum_with_while(num: u32, count: u32, skip: Skip) -> Result<Summary, FactorialError> {
+    let mut iterations = Vec::new();
+    let mut skipped = Vec::new();
+    let mut count = count;
+    let mut result: u32 = 0;
+
+    while count > 0 {
+        if skip.matches(count) {
+            skipped.push(count);
+            count -= 1;
+            continue;
+        }
+        let mut current = num;
+        let mut factorial: u32 = 1;
+        while current > 1 {
+            factorial = factorial.checked_mul(current).ok_or(FactorialError::Overflow { n: num })?;
+            current -= 1;
+        }
+        result = result.checked_add(factorial).ok_or(FactorialError::SumOverflow)?;
+        iterations.push(Iteration { count, factorial });
+        count -= 1;
+    }
+
+    Ok(Summary { iterations, skipped, result })
+}
+
+/// The same computation with `for` loops over ranges, no counters to update by hand.
+pub fn sum_with_for(num: u32, count: u32, skip: Skip) -> Result<Summary, FactorialError> {
+    let mut iterations = Vec::new();
+    let mut skipped = Vec::new();
+    let mut result: u32 = 0;
+
+    for count in (1..=count).rev() {
+        if skip.matches(count) {
+            skipped.push(count);
+            continue;
+        }
+        let mut factorial: u32 = 1;
+        for current in 2..=num {
+            factorial = factorial.checked_mul(current).ok_or(FactorialError::Overflow { n: num })?;
+        }
+        result = result.checked_add(factorial).ok_or(FactorialError::SumOverflow)?;
+        iterations.push(Iteration { count, factorial });
+    }
+
+    Ok(Summary { iterations, skipped, result })
+}
+
+/// The same computation with ranges and iterator adapters instead of counters.
+pub fn sum_with_iterator(num: u32, count: u32, skip: Skip) -> Result<Summary, FactorialError> {
+    let (skipped, kept): (Vec<u32>, Vec<u32>) = (1..=count).rev().partition(|count| skip.matches(*count));
+
+    let iterations = kept
+        .into_iter()
+        .map(|count| {
+            let factorial = (1..=num).try_fold(1u32, |factorial, current| {
+                factorial.checked_mul(current).ok_or(FactorialError::Overflow { n: num })
+            })?;
+            Ok(Iteration { count, factorial })
+        })
+        .collect::<Result<Vec<Iteration>, FactorialError>>()?;
+
+    let result = iterations.iter().try_fold(0u32, |result, iteration| {
+        result.checked_add(iteration.factorial).ok_or(FactorialError::SumOverflow)
+    })?;
+
+    Ok(Summary { iterations, skipped, result })
+}
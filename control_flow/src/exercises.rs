@@ -0,0 +1,81 @@
+//! The exercises at the end of the Rust Book's control flow chapter.
+
+pub fn fahrenheit_to_celsius(fahrenheit: f64) -> f64 {
+    (fahrenheit - 32.0) * 5.0 / 9.0
+}
+
+pub fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+/// The nth Fibonacci number counting from `fibonacci(0) == 0`, `None` once it
+/// no longer fits in a u64 (after n = 93).
+pub fn fibonacci(n: u32) -> Option<u64> {
+    if n == 0 {
+        return Some(0);
+    }
+
+    let mut previous: u64 = 0;
+    let mut current: u64 = 1;
+    for _ in 1..n {
+        let next = previous.checked_add(current)?;
+        previous = current;
+        current = next;
+    }
+
+    Some(current)
+}
+
+pub fn fizzbuzz(number: u32) -> String {
+    match (number % 3, number % 5) {
+        (0, 0) => String::from("FizzBuzz"),
+        (0, _) => String::from("Fizz"),
+        (_, 0) => String::from("Buzz"),
+        _ => number.to_string(),
+    }
+}
+
+pub fn fizzbuzz_up_to(last: u32) -> Vec<String> {
+    (1..=last).map(fizzbuzz).collect()
+}
+
+const ORDINALS: [&str; 12] = [
+    "first", "second", "third", "fourth", "fifth", "sixth",
+    "seventh", "eighth", "ninth", "tenth", "eleventh", "twelfth",
+];
+
+const GIFTS: [&str; 12] = [
+    "a partridge in a pear tree",
+    "two turtle doves",
+    "three French hens",
+    "four calling birds",
+    "five gold rings",
+    "six geese a-laying",
+    "seven swans a-swimming",
+    "eight maids a-milking",
+    "nine ladies dancing",
+    "ten lords a-leaping",
+    "eleven pipers piping",
+    "twelve drummers drumming",
+];
+
+/// Verse for `day` 1 to 12, the gifts are counted back down to the partridge.
+/// `None` for any other day.
+pub fn christmas_verse(day: usize) -> Option<String> {
+    let ordinal = ORDINALS.get(day.checked_sub(1)?)?;
+    let mut verse = format!("On the {} day of Christmas my true love sent to me:\n", ordinal);
+
+    for gift in (0..day).rev() {
+        if gift == 0 && day > 1 {
+            verse.push_str("And ");
+        }
+        verse.push_str(GIFTS[gift]);
+        verse.push('\n');
+    }
+
+    Some(verse)
+}
+
+pub fn twelve_days_of_christmas() -> String {
+    (1..=12).filter_map(christmas_verse).collect::<Vec<String>>().join("\n")
+}
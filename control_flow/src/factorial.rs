@@ -0,0 +1,45 @@
+use std::error::Error;
+use std::fmt;
+
+use num_bigint::BigUint;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactorialError {
+    /// `n!` does not fit in the integer type.
+    Overflow { n: u32 },
+    /// Adding the factorials together does not fit in the integer type.
+    SumOverflow,
+}
+
+impl fmt::Display for FactorialError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FactorialError::Overflow { n } => write!(f, "{}! is too big for a u32, use `big-factorial {}` for the exact value", n, n),
+            FactorialError::SumOverflow => write!(f, "the sum of the factorials is too big for a u32"),
+        }
+    }
+}
+
+impl Error for FactorialError {}
+
+pub fn factorial(n: u32) -> Result<u32, FactorialError> {
+    let mut num = n;
+    let mut factorial: u32 = 1;
+
+    loop {
+        if num <= 1 {
+            break Ok(factorial);
+        }
+
+        factorial = match factorial.checked_mul(num) {
+            Some(value) => value,
+            None => break Err(FactorialError::Overflow { n }),
+        };
+        num -= 1;
+    }
+}
+
+/// Exact factorial with no upper limit.
+pub fn big_factorial(n: u32) -> BigUint {
+    (1..=n).fold(BigUint::from(1u32), |factorial, num| factorial * num)
+}
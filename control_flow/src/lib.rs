@@ -0,0 +1,11 @@
+pub mod cli;
+pub mod counting;
+pub mod exercises;
+pub mod factorial;
+pub mod parallel;
+
+pub use counting::{sum_with_for, sum_with_iterator, sum_with_loop, sum_with_while, Iteration, Skip, Summary};
+pub use factorial::{big_factorial, factorial, FactorialError};
+pub use parallel::sum_with_threads;
+#[cfg(feature = "rayon")]
+pub use parallel::sum_with_rayon;
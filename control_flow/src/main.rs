@@ -1,25 +1,79 @@
+use std::env;
+use std::process;
+
+use control_flow::cli::{self, Command, Format, Mode, Options};
+use control_flow::exercises;
+use control_flow::{FactorialError, Summary};
+
 fn main() {
-    let mut count: u32 = 4;
-    let mut result : u32 = 0;
-    'counting_up: loop {
-        if count == 0 {
-            break;
+    let options = match cli::parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("Error: {}\n\n{}", err, cli::USAGE);
+            process::exit(2);
         }
-        let mut num: u32 = 10;
-        let mut factorial : u32 = 1; 
-        result += loop {
-            if num == 1 {
-                break factorial;
+    };
+
+    if options.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
+
+    match options.command {
+        Command::Factorial => {
+            if let Err(err) = run(&options) {
+                eprintln!("Error: {}", err);
+                process::exit(1);
+            }
+        },
+        Command::FizzBuzz(last) => {
+            for line in exercises::fizzbuzz_up_to(last) {
+                println!("{}", line);
+            }
+        },
+        Command::Fibonacci(n) => match exercises::fibonacci(n) {
+            Some(number) => println!("Fibonacci({}) = {}", n, number),
+            None => {
+                eprintln!("Error: Fibonacci({}) is too big for a u64", n);
+                process::exit(1);
+            }
+        },
+        Command::ToCelsius(fahrenheit) => {
+            println!("{}°F = {:.2}°C", fahrenheit, exercises::fahrenheit_to_celsius(fahrenheit));
+        },
+        Command::ToFahrenheit(celsius) => {
+            println!("{}°C = {:.2}°F", celsius, exercises::celsius_to_fahrenheit(celsius));
+        },
+        Command::Christmas => print!("{}", exercises::twelve_days_of_christmas()),
+        Command::BigFactorial(n) => println!("{}! = {}", n, control_flow::big_factorial(n)),
+    }
+}
+
+fn summarize(options: &Options) -> Result<Summary, FactorialError> {
+    match options.mode {
+        Mode::Sequential => control_flow::sum_with_loop(options.num, options.count, options.skip),
+        Mode::Threads(threads) => control_flow::sum_with_threads(options.num, options.count, options.skip, threads),
+        #[cfg(feature = "rayon")]
+        Mode::Rayon => control_flow::sum_with_rayon(options.num, options.count, options.skip),
+        #[cfg(not(feature = "rayon"))]
+        Mode::Rayon => unreachable!("--rayon is rejected without the rayon feature"),
+    }
+}
+
+fn run(options: &Options) -> Result<(), FactorialError> {
+    let summary = summarize(options)?;
+
+    match options.format {
+        Format::Human => {
+            for iteration in &summary.iterations {
+                println!("count = {}, factorial : {}", iteration.count, iteration.factorial);
             }
-            if count == 0 {
-                continue 'counting_up;
+            if !summary.skipped.is_empty() {
+                println!("Skipped counts : {:?}", summary.skipped);
             }
-            factorial *= num;
-            num -= 1;
-        };
-        println!("count = {count}, factorial : {factorial}");
-        count -= 1;
-    
+            println!("Result = {}", summary.result);
+        },
+        Format::Json => println!("{}", summary.to_json(options.num, options.count)),
     }
-    println!("Result = {result}");    
+    Ok(())
 }
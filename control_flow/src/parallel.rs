@@ -0,0 +1,60 @@
+use std::thread;
+
+use crate::{factorial, FactorialError, Iteration, Skip, Summary};
+
+fn summarize(iterations: Vec<Iteration>, skipped: Vec<u32>) -> Result<Summary, FactorialError> {
+    let result = iterations.iter().try_fold(0u32, |result, iteration| {
+        result.checked_add(iteration.factorial).ok_or(FactorialError::SumOverflow)
+    })?;
+
+    Ok(Summary { iterations, skipped, result })
+}
+
+/// Same result as `sum_with_loop`, with the counts split into chunks and every
+/// chunk computed on its own `std::thread`. `workers` is at least 1.
+pub fn sum_with_threads(num: u32, count: u32, skip: Skip, workers: usize) -> Result<Summary, FactorialError> {
+    let (skipped, kept): (Vec<u32>, Vec<u32>) = (1..=count).rev().partition(|count| skip.matches(*count));
+
+    let chunk_size = kept.len().div_ceil(workers.max(1)).max(1);
+
+    let chunks: Vec<Result<Vec<Iteration>, FactorialError>> = thread::scope(|scope| {
+        let handles: Vec<_> = kept
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|&count| Ok(Iteration { count, factorial: factorial(num)? }))
+                        .collect()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("factorial worker panicked"))
+            .collect()
+    });
+
+    let mut iterations = Vec::with_capacity(kept.len());
+    for chunk in chunks {
+        iterations.extend(chunk?);
+    }
+
+    summarize(iterations, skipped)
+}
+
+/// Same result as `sum_with_loop`, using rayon's parallel iterators.
+#[cfg(feature = "rayon")]
+pub fn sum_with_rayon(num: u32, count: u32, skip: Skip) -> Result<Summary, FactorialError> {
+    use rayon::prelude::*;
+
+    let (skipped, kept): (Vec<u32>, Vec<u32>) = (1..=count).rev().partition(|count| skip.matches(*count));
+
+    let iterations = kept
+        .par_iter()
+        .map(|&count| Ok(Iteration { count, factorial: factorial(num)? }))
+        .collect::<Result<Vec<Iteration>, FactorialError>>()?;
+
+    summarize(iterations, skipped)
+}
@@ -0,0 +1,73 @@
+use control_flow::cli::{parse_args, Command, Format, Mode, Options};
+use control_flow::{sum_with_loop, Skip};
+
+fn parse(args: &[&str]) -> Result<Options, String> {
+    parse_args(args.iter().map(|arg| arg.to_string()))
+}
+
+#[test]
+fn defaults_match_the_readme_example() {
+    let options = parse(&[]).unwrap();
+
+    assert_eq!(options.command, Command::Factorial);
+    assert_eq!((options.num, options.count), (10, 4));
+    assert_eq!(options.skip, Skip::Never);
+    assert_eq!(options.mode, Mode::Sequential);
+    assert_eq!(options.format, Format::Human);
+    assert!(!options.help);
+}
+
+#[test]
+fn factorial_options_are_parsed() {
+    let options = parse(&["--num", "5", "--count", "3", "--skip", "odd", "--threads", "2", "--format", "json"]).unwrap();
+
+    assert_eq!((options.num, options.count), (5, 3));
+    assert_eq!(options.skip, Skip::Odd);
+    assert_eq!(options.mode, Mode::Threads(2));
+    assert_eq!(options.format, Format::Json);
+    assert!(parse(&["--help"]).unwrap().help);
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+    assert!(parse(&["--num", "0"]).is_err());
+    assert!(parse(&["--num", "-3"]).is_err());
+    assert!(parse(&["--num"]).is_err());
+    assert!(parse(&["--count", "many"]).is_err());
+    assert!(parse(&["--skip", "prime"]).is_err());
+    assert!(parse(&["--threads", "0"]).is_err());
+    assert!(parse(&["--format", "xml"]).is_err());
+    assert!(parse(&["--verbose"]).is_err());
+    assert_eq!(parse(&["--rayon"]).is_ok(), cfg!(feature = "rayon"));
+}
+
+#[test]
+fn exercises_take_a_value() {
+    assert_eq!(parse(&["fizzbuzz", "15"]).unwrap().command, Command::FizzBuzz(15));
+    assert_eq!(parse(&["to-celsius", "98.6"]).unwrap().command, Command::ToCelsius(98.6));
+    assert_eq!(parse(&["christmas"]).unwrap().command, Command::Christmas);
+    assert_eq!(parse(&["fizzbuzz"]), Err(String::from("fizzbuzz needs a value")));
+    assert!(parse(&["fibonacci", "x"]).is_err());
+}
+
+#[test]
+fn exercises_reject_factorial_options() {
+    assert_eq!(
+        parse(&["fizzbuzz", "5", "--format", "json"]),
+        Err(String::from("--format only applies to the factorial sum, not to fizzbuzz"))
+    );
+    assert!(parse(&["fizzbuzz", "5", "--num", "3"]).is_err());
+    assert!(parse(&["christmas", "--skip", "odd"]).is_err());
+    assert!(parse(&["fibonacci", "10", "--threads", "2"]).is_err());
+    assert!(parse(&["fibonacci", "10", "--help"]).unwrap().help);
+}
+
+#[test]
+fn json_output_lists_iterations_and_skipped_counts() {
+    let summary = sum_with_loop(3, 3, Skip::Even).unwrap();
+
+    assert_eq!(
+        summary.to_json(3, 3),
+        r#"{"num": 3, "count": 3, "iterations": [{"count": 3, "factorial": 6}, {"count": 1, "factorial": 6}], "skipped": [2], "result": 12}"#
+    );
+}
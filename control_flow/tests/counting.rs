@@ -0,0 +1,71 @@
+use control_flow::{sum_with_for, sum_with_iterator, sum_with_loop, sum_with_threads, sum_with_while, FactorialError, Skip};
+
+const SKIPS: [Skip; 3] = [Skip::Never, Skip::Odd, Skip::Even];
+
+#[test]
+fn all_styles_agree_with_the_readme_example() {
+    let summary = sum_with_loop(10, 4, Skip::Never).unwrap();
+
+    assert_eq!(summary.result, 14_515_200);
+    assert_eq!(summary.iterations.len(), 4);
+    assert_eq!(sum_with_while(10, 4, Skip::Never).unwrap(), summary);
+    assert_eq!(sum_with_for(10, 4, Skip::Never).unwrap(), summary);
+    assert_eq!(sum_with_iterator(10, 4, Skip::Never).unwrap(), summary);
+}
+
+#[test]
+fn all_styles_agree_on_small_inputs() {
+    for skip in SKIPS {
+        for num in 0..=14 {
+            for count in 0..=5 {
+                let expected = sum_with_loop(num, count, skip);
+                assert_eq!(sum_with_while(num, count, skip), expected, "num = {num}, count = {count}, {skip:?}");
+                assert_eq!(sum_with_for(num, count, skip), expected, "num = {num}, count = {count}, {skip:?}");
+                assert_eq!(sum_with_iterator(num, count, skip), expected, "num = {num}, count = {count}, {skip:?}");
+                for workers in 1..=4 {
+                    assert_eq!(sum_with_threads(num, count, skip, workers), expected, "{workers} workers");
+                }
+                #[cfg(feature = "rayon")]
+                assert_eq!(control_flow::sum_with_rayon(num, count, skip), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn all_styles_report_the_same_overflow() {
+    assert_eq!(sum_with_loop(13, 1, Skip::Never), Err(FactorialError::Overflow { n: 13 }));
+    assert_eq!(sum_with_while(13, 1, Skip::Never), Err(FactorialError::Overflow { n: 13 }));
+    assert_eq!(sum_with_for(13, 1, Skip::Never), Err(FactorialError::Overflow { n: 13 }));
+    assert_eq!(sum_with_iterator(13, 1, Skip::Never), Err(FactorialError::Overflow { n: 13 }));
+
+    assert_eq!(sum_with_loop(12, 9, Skip::Never), Err(FactorialError::SumOverflow));
+    assert_eq!(sum_with_while(12, 9, Skip::Never), Err(FactorialError::SumOverflow));
+    assert_eq!(sum_with_for(12, 9, Skip::Never), Err(FactorialError::SumOverflow));
+    assert_eq!(sum_with_iterator(12, 9, Skip::Never), Err(FactorialError::SumOverflow));
+}
+
+#[test]
+fn labeled_continue_skips_odd_counts() {
+    let summary = sum_with_loop(5, 4, Skip::Odd).unwrap();
+
+    let counts: Vec<u32> = summary.iterations.iter().map(|iteration| iteration.count).collect();
+    assert_eq!(counts, vec![4, 2]);
+    assert_eq!(summary.skipped, vec![3, 1]);
+    assert_eq!(summary.result, 240);
+}
+
+#[test]
+fn labeled_break_ends_the_loop_when_everything_is_skipped() {
+    let summary = sum_with_loop(5, 1, Skip::Odd).unwrap();
+
+    assert!(summary.iterations.is_empty());
+    assert_eq!(summary.skipped, vec![1]);
+    assert_eq!(summary.result, 0);
+}
+
+#[test]
+fn skipped_counts_never_overflow() {
+    // 13! overflows a u32 but the only count is skipped before it is computed.
+    assert_eq!(sum_with_loop(13, 1, Skip::Odd).unwrap().result, 0);
+}
@@ -0,0 +1,50 @@
+use control_flow::exercises::*;
+
+#[test]
+fn temperature_conversions_round_trip() {
+    assert_eq!(fahrenheit_to_celsius(32.0), 0.0);
+    assert_eq!(fahrenheit_to_celsius(212.0), 100.0);
+    assert_eq!(celsius_to_fahrenheit(-40.0), -40.0);
+
+    for celsius in [-273.15, -10.0, 0.0, 21.5, 100.0] {
+        assert!((fahrenheit_to_celsius(celsius_to_fahrenheit(celsius)) - celsius).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn fibonacci_numbers() {
+    let first: Vec<u64> = (0..10).map(|n| fibonacci(n).unwrap()).collect();
+    assert_eq!(first, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+
+    assert_eq!(fibonacci(93), Some(12_200_160_415_121_876_738));
+    assert_eq!(fibonacci(94), None);
+}
+
+#[test]
+fn fizzbuzz_replaces_multiples_of_three_and_five() {
+    assert_eq!(
+        fizzbuzz_up_to(15),
+        vec!["1", "2", "Fizz", "4", "Buzz", "Fizz", "7", "8", "Fizz", "Buzz", "11", "Fizz", "13", "14", "FizzBuzz"]
+    );
+    assert!(fizzbuzz_up_to(0).is_empty());
+}
+
+#[test]
+fn twelve_days_of_christmas_counts_down_the_gifts() {
+    assert_eq!(
+        christmas_verse(1).unwrap(),
+        "On the first day of Christmas my true love sent to me:\na partridge in a pear tree\n"
+    );
+    assert_eq!(
+        christmas_verse(3).unwrap(),
+        "On the third day of Christmas my true love sent to me:\nthree French hens\ntwo turtle doves\nAnd a partridge in a pear tree\n"
+    );
+
+    assert_eq!(christmas_verse(0), None);
+    assert_eq!(christmas_verse(13), None);
+
+    let song = twelve_days_of_christmas();
+    assert_eq!(song.matches("On the").count(), 12);
+    assert!(song.contains("twelfth day"));
+    assert_eq!(song.matches("partridge").count(), 12);
+}
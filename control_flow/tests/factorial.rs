@@ -0,0 +1,23 @@
+use control_flow::{big_factorial, factorial, FactorialError};
+use num_bigint::BigUint;
+
+#[test]
+fn factorial_of_twelve_fits_in_a_u32() {
+    assert_eq!(factorial(0), Ok(1));
+    assert_eq!(factorial(1), Ok(1));
+    assert_eq!(factorial(10), Ok(3_628_800));
+    assert_eq!(factorial(12), Ok(479_001_600));
+}
+
+#[test]
+fn factorial_of_thirteen_overflows() {
+    assert_eq!(factorial(13), Err(FactorialError::Overflow { n: 13 }));
+    assert_eq!(factorial(u32::MAX), Err(FactorialError::Overflow { n: u32::MAX }));
+}
+
+#[test]
+fn big_factorial_is_exact() {
+    assert_eq!(big_factorial(0), BigUint::from(1u32));
+    assert_eq!(big_factorial(12), BigUint::from(479_001_600u32));
+    assert_eq!(big_factorial(25), "15511210043330985984000000".parse::<BigUint>().unwrap());
+}
@@ -0,0 +1,156 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+pub const TYPE_NAMES: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "isize",
+    "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArithmeticError {
+    UnknownType(String),
+    InvalidOperand { value: String, type_name: String },
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArithmeticError::UnknownType(name) => {
+                write!(f, "'{}' is not an integer type, use one of {}", name, TYPE_NAMES.join(", "))
+            },
+            ArithmeticError::InvalidOperand { value, type_name } => {
+                write!(f, "'{}' is not a valid {}", value, type_name)
+            },
+        }
+    }
+}
+
+impl Error for ArithmeticError {}
+
+/// One operation under every overflow handling mode, already formatted for printing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationRow {
+    pub operation: &'static str,
+    pub checked: String,
+    pub wrapping: String,
+    pub saturating: String,
+    pub overflowing: String,
+}
+
+const PANICS: &str = "panics";
+const NOT_AVAILABLE: &str = "n/a";
+
+fn checked<T: fmt::Display>(value: Option<T>) -> String {
+    match value {
+        Some(value) => format!("Some({})", value),
+        None => String::from("None"),
+    }
+}
+
+fn overflowing<T: fmt::Display>((value, overflowed): (T, bool)) -> String {
+    format!("({}, {})", value, overflowed)
+}
+
+fn parse<T: FromStr>(value: &str, type_name: &str) -> Result<T, ArithmeticError> {
+    value.replace('_', "").parse().map_err(|_| ArithmeticError::InvalidOperand {
+        value: value.to_string(),
+        type_name: type_name.to_string(),
+    })
+}
+
+macro_rules! operation_rows {
+    ($t:ty, $type_name:expr, $a:expr, $b:expr) => {{
+        let a: $t = parse($a, $type_name)?;
+        let b: $t = parse($b, $type_name)?;
+
+        // Every mode except checked_ panics when dividing by zero.
+        let by_zero = b == 0;
+
+        vec![
+            OperationRow {
+                operation: "add",
+                checked: checked(a.checked_add(b)),
+                wrapping: a.wrapping_add(b).to_string(),
+                saturating: a.saturating_add(b).to_string(),
+                overflowing: overflowing(a.overflowing_add(b)),
+            },
+            OperationRow {
+                operation: "sub",
+                checked: checked(a.checked_sub(b)),
+                wrapping: a.wrapping_sub(b).to_string(),
+                saturating: a.saturating_sub(b).to_string(),
+                overflowing: overflowing(a.overflowing_sub(b)),
+            },
+            OperationRow {
+                operation: "mul",
+                checked: checked(a.checked_mul(b)),
+                wrapping: a.wrapping_mul(b).to_string(),
+                saturating: a.saturating_mul(b).to_string(),
+                overflowing: overflowing(a.overflowing_mul(b)),
+            },
+            OperationRow {
+                operation: "div",
+                checked: checked(a.checked_div(b)),
+                wrapping: if by_zero { String::from(PANICS) } else { a.wrapping_div(b).to_string() },
+                saturating: if by_zero { String::from(PANICS) } else { a.saturating_div(b).to_string() },
+                overflowing: if by_zero { String::from(PANICS) } else { overflowing(a.overflowing_div(b)) },
+            },
+            OperationRow {
+                operation: "rem",
+                checked: checked(a.checked_rem(b)),
+                wrapping: if by_zero { String::from(PANICS) } else { a.wrapping_rem(b).to_string() },
+                saturating: String::from(NOT_AVAILABLE),
+                overflowing: if by_zero { String::from(PANICS) } else { overflowing(a.overflowing_rem(b)) },
+            },
+        ]
+    }};
+}
+
+/// Runs `a + b`, `a - b`, `a * b`, `a / b` and `a % b` with the checked_, wrapping_,
+/// saturating_ and overflowing_ methods of the named integer type.
+pub fn operation_table(type_name: &str, a: &str, b: &str) -> Result<Vec<OperationRow>, ArithmeticError> {
+    let rows = match type_name {
+        "i8" => operation_rows!(i8, type_name, a, b),
+        "i16" => operation_rows!(i16, type_name, a, b),
+        "i32" => operation_rows!(i32, type_name, a, b),
+        "i64" => operation_rows!(i64, type_name, a, b),
+        "i128" => operation_rows!(i128, type_name, a, b),
+        "isize" => operation_rows!(isize, type_name, a, b),
+        "u8" => operation_rows!(u8, type_name, a, b),
+        "u16" => operation_rows!(u16, type_name, a, b),
+        "u32" => operation_rows!(u32, type_name, a, b),
+        "u64" => operation_rows!(u64, type_name, a, b),
+        "u128" => operation_rows!(u128, type_name, a, b),
+        "usize" => operation_rows!(usize, type_name, a, b),
+        _ => return Err(ArithmeticError::UnknownType(type_name.to_string())),
+    };
+
+    Ok(rows)
+}
+
+pub fn print_operation_table(type_name: &str, a: &str, b: &str) -> Result<(), ArithmeticError> {
+    let rows = operation_table(type_name, a, b)?;
+
+    println!("{} with a = {} and b = {}", type_name, a, b);
+    println!();
+    let width = |column: fn(&OperationRow) -> &String, title: &str| {
+        rows.iter().map(|row| column(row).len()).max().unwrap_or(0).max(title.len())
+    };
+    let checked_width = width(|row| &row.checked, "checked_");
+    let wrapping_width = width(|row| &row.wrapping, "wrapping_");
+    let saturating_width = width(|row| &row.saturating, "saturating_");
+
+    println!(
+        "{:<4} {:<checked_width$} {:<wrapping_width$} {:<saturating_width$} overflowing_",
+        "", "checked_", "wrapping_", "saturating_"
+    );
+    for row in &rows {
+        println!(
+            "{:<4} {:<checked_width$} {:<wrapping_width$} {:<saturating_width$} {}",
+            row.operation, row.checked, row.wrapping, row.saturating, row.overflowing
+        );
+    }
+
+    Ok(())
+}
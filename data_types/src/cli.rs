@@ -0,0 +1,96 @@
+use crate::decimal::RoundingMode;
+use crate::operator::Operator;
+
+pub const USAGE: &str = "Usage: data_types [command]
+
+Without a command the floating-point and numeric operation lessons are printed.
+
+Commands:
+    precision <literal>    Show how a decimal literal is stored as f32 and f64
+    arithmetic <type> <a> <b>
+                           Run + - * / % on two integers in every overflow mode
+    convert <type> <value> Convert a number to every numeric type with `as` and with checks
+    literal <literal>      Parse an integer literal like 0xff, 0b1111_0000, b'A' or 58u8
+    floats                 NaN, infinities, signed zero, subnormals and comparing floats
+    layout                 Size, alignment, bits and range of every primitive type
+    decimal <a> <op> <b> [--scale <digits>] [--rounding <mode>]
+                           Compare f64 with exact Decimal arithmetic, op is + - * or /
+                           Division keeps 10 digits with half-even rounding unless told otherwise
+    rational <a> <op> <b>  Compare f64 with exact fractions like 5/2, op is + - * or /
+    -h, --help             Print this help";
+
+pub const DEFAULT_DIVISION_SCALE: u32 = 10;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum Command {
+    #[default]
+    Lessons,
+    Precision(String),
+    Arithmetic { type_name: String, a: String, b: String },
+    Convert { type_name: String, value: String },
+    Literal(String),
+    Floats,
+    Layout,
+    Decimal { a: String, operator: Operator, b: String, scale: u32, rounding: RoundingMode },
+    Rational { a: String, operator: Operator, b: String },
+    Help,
+}
+
+pub fn parse_args<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let args: Vec<String> = args.into_iter().collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        [] => Ok(Command::Lessons),
+        ["-h" | "--help"] => Ok(Command::Help),
+        ["precision", literal] => Ok(Command::Precision(literal.to_string())),
+        ["precision", ..] => Err(String::from("precision needs exactly one literal")),
+        ["arithmetic", type_name, a, b] => Ok(Command::Arithmetic {
+            type_name: type_name.to_string(),
+            a: a.to_string(),
+            b: b.to_string(),
+        }),
+        ["arithmetic", ..] => Err(String::from("arithmetic needs a type and two operands")),
+        ["convert", type_name, value] => Ok(Command::Convert {
+            type_name: type_name.to_string(),
+            value: value.to_string(),
+        }),
+        ["convert", ..] => Err(String::from("convert needs a type and a value")),
+        ["literal", literal] => Ok(Command::Literal(literal.to_string())),
+        ["literal", ..] => Err(String::from("literal needs exactly one literal")),
+        ["floats"] => Ok(Command::Floats),
+        ["layout"] => Ok(Command::Layout),
+        ["decimal", a, operator, b, options @ ..] => {
+            let mut scale = DEFAULT_DIVISION_SCALE;
+            let mut rounding = RoundingMode::default();
+            for option in options.chunks(2) {
+                match option {
+                    ["--scale", value] => {
+                        scale = value.parse().map_err(|_| format!("'{}' is not a valid scale", value))?;
+                    },
+                    ["--rounding", value] => rounding = value.parse()?,
+                    _ => return Err(format!("Unknown decimal option '{}'", option.join(" "))),
+                }
+            }
+
+            Ok(Command::Decimal {
+                a: a.to_string(),
+                operator: operator.parse()?,
+                b: b.to_string(),
+                scale,
+                rounding,
+            })
+        },
+        ["rational", a, operator, b] => Ok(Command::Rational {
+            a: a.to_string(),
+            operator: operator.parse()?,
+            b: b.to_string(),
+        }),
+        ["rational", ..] => Err(String::from("rational needs two fractions and an operator, like 5 / 11/2")),
+        ["decimal", ..] => Err(String::from("decimal needs two numbers and an operator, like 5.5 - 6.23")),
+        [command, ..] => Err(format!("Unknown command '{}'", command)),
+    }
+}
@@ -0,0 +1,307 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::arithmetic;
+
+pub const FLOAT_TYPE_NAMES: [&str; 2] = ["f32", "f64"];
+
+/// The integer types from `arithmetic::TYPE_NAMES` followed by the float types.
+pub fn type_names() -> impl Iterator<Item = &'static str> {
+    arithmetic::TYPE_NAMES.into_iter().chain(FLOAT_TYPE_NAMES)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConversionError {
+    UnknownType(String),
+    InvalidValue { value: String, type_name: String },
+    /// The value is outside the range of the target type.
+    OutOfRange { value: String, to: &'static str },
+    /// Converting to an integer would drop the fractional part.
+    Fractional { value: String, to: &'static str },
+    /// NaN and infinity have no integer equivalent.
+    NotFinite { value: String, to: &'static str },
+    /// The target type cannot hold every digit, the value would be rounded.
+    PrecisionLoss { value: String, to: &'static str },
+}
+
+impl ConversionError {
+    fn with_value(self, value: String) -> ConversionError {
+        match self {
+            ConversionError::OutOfRange { to, .. } => ConversionError::OutOfRange { value, to },
+            ConversionError::Fractional { to, .. } => ConversionError::Fractional { value, to },
+            ConversionError::NotFinite { to, .. } => ConversionError::NotFinite { value, to },
+            ConversionError::PrecisionLoss { to, .. } => ConversionError::PrecisionLoss { value, to },
+            err => err,
+        }
+    }
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConversionError::UnknownType(name) => {
+                write!(f, "'{}' is not a numeric type, use one of {}", name, type_names().collect::<Vec<_>>().join(", "))
+            },
+            ConversionError::InvalidValue { value, type_name } => write!(f, "'{}' is not a valid {}", value, type_name),
+            ConversionError::OutOfRange { value, to } => write!(f, "{} is out of range for {}", value, to),
+            ConversionError::Fractional { value, to } => write!(f, "{} has a fractional part, {} cannot hold it", value, to),
+            ConversionError::NotFinite { value, to } => write!(f, "{} has no {} equivalent", value, to),
+            ConversionError::PrecisionLoss { value, to } => write!(f, "{} would be rounded in {}", value, to),
+        }
+    }
+}
+
+impl Error for ConversionError {}
+
+/// Integers with every digit, floats in the `{:?}` form that switches to an exponent,
+/// so `1e300` stays `1e300` instead of 301 digits.
+trait ToText {
+    fn to_text(&self) -> String;
+}
+
+macro_rules! to_text {
+    ($format:literal; $($t:ty),*) => {
+        $( impl ToText for $t {
+            fn to_text(&self) -> String {
+                format!($format, self)
+            }
+        } )*
+    };
+}
+
+to_text!("{}"; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+to_text!("{:?}"; f32, f64);
+
+/// Like `TryFrom`, but also covers the float conversions the standard library
+/// only offers through `as`.
+pub trait SafeFrom<T>: Sized {
+    fn safe_from(value: T) -> Result<Self, ConversionError>;
+}
+
+macro_rules! int_to_int {
+    ($src:ty => $($dst:ty),*) => {
+        $(
+            impl SafeFrom<$src> for $dst {
+                fn safe_from(value: $src) -> Result<Self, ConversionError> {
+                    <$dst>::try_from(value).map_err(|_| ConversionError::OutOfRange {
+                        value: value.to_string(),
+                        to: stringify!($dst),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! int_to_all_ints {
+    ($($src:ty),*) => {
+        $( int_to_int!($src => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize); )*
+    };
+}
+
+int_to_all_ints!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! float_and_int {
+    ($($int:ty),*) => {
+        $(
+            impl SafeFrom<f64> for $int {
+                fn safe_from(value: f64) -> Result<Self, ConversionError> {
+                    let to = stringify!($int);
+                    if !value.is_finite() {
+                        return Err(ConversionError::NotFinite { value: value.to_text(), to });
+                    }
+                    if value.fract() != 0.0 {
+                        return Err(ConversionError::Fractional { value: value.to_text(), to });
+                    }
+                    // MAX as f64 is either exact or rounds up to the next power of two,
+                    // adding one gives the first value that is too big in both cases.
+                    if value < <$int>::MIN as f64 || value >= <$int>::MAX as f64 + 1.0 {
+                        return Err(ConversionError::OutOfRange { value: value.to_text(), to });
+                    }
+                    Ok(value as $int)
+                }
+            }
+
+            impl SafeFrom<f32> for $int {
+                fn safe_from(value: f32) -> Result<Self, ConversionError> {
+                    <$int>::safe_from(value as f64).map_err(|err| err.with_value(value.to_text()))
+                }
+            }
+
+            impl SafeFrom<$int> for f64 {
+                fn safe_from(value: $int) -> Result<Self, ConversionError> {
+                    let converted = value as f64;
+                    match <$int>::safe_from(converted) {
+                        Ok(back) if back == value => Ok(converted),
+                        _ => Err(ConversionError::PrecisionLoss { value: value.to_string(), to: "f64" }),
+                    }
+                }
+            }
+
+            impl SafeFrom<$int> for f32 {
+                fn safe_from(value: $int) -> Result<Self, ConversionError> {
+                    let converted = value as f32;
+                    match <$int>::safe_from(converted as f64) {
+                        Ok(back) if back == value => Ok(converted),
+                        _ => Err(ConversionError::PrecisionLoss { value: value.to_string(), to: "f32" }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+float_and_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl SafeFrom<f32> for f32 {
+    fn safe_from(value: f32) -> Result<Self, ConversionError> {
+        Ok(value)
+    }
+}
+
+impl SafeFrom<f64> for f64 {
+    fn safe_from(value: f64) -> Result<Self, ConversionError> {
+        Ok(value)
+    }
+}
+
+impl SafeFrom<f32> for f64 {
+    fn safe_from(value: f32) -> Result<Self, ConversionError> {
+        Ok(f64::from(value))
+    }
+}
+
+impl SafeFrom<f64> for f32 {
+    fn safe_from(value: f64) -> Result<Self, ConversionError> {
+        let converted = value as f32;
+        if value.is_nan() || converted as f64 == value {
+            Ok(converted)
+        } else if value.is_finite() && converted.is_infinite() {
+            Err(ConversionError::OutOfRange { value: value.to_text(), to: "f32" })
+        } else {
+            Err(ConversionError::PrecisionLoss { value: value.to_text(), to: "f32" })
+        }
+    }
+}
+
+/// `SafeFrom` with the target type picked by inference, like `TryInto`.
+pub fn convert<T, U: SafeFrom<T>>(value: T) -> Result<U, ConversionError> {
+    U::safe_from(value)
+}
+
+macro_rules! lossless {
+    ($($src:ty => [$($dst:ty),*]),* $(,)?) => {
+        /// Every pair with a standard library `From` impl, so the conversion can never fail.
+        pub const LOSSLESS: &[(&str, &str)] = &[$($((stringify!($src), stringify!($dst)),)*)*];
+
+        // Fails to compile if a pair above has no `From` impl.
+        const _: fn() = || {
+            $($(let _: fn($src) -> $dst = <$dst as From<$src>>::from;)*)*
+        };
+    };
+}
+
+lossless! {
+    i8 => [i8, i16, i32, i64, i128, isize, f32, f64],
+    i16 => [i16, i32, i64, i128, isize, f32, f64],
+    i32 => [i32, i64, i128, f64],
+    i64 => [i64, i128],
+    i128 => [i128],
+    isize => [isize],
+    u8 => [u8, u16, u32, u64, u128, usize, i16, i32, i64, i128, isize, f32, f64],
+    u16 => [u16, u32, u64, u128, usize, i32, i64, i128, f32, f64],
+    u32 => [u32, u64, u128, i64, i128, f64],
+    u64 => [u64, u128, i128],
+    u128 => [u128],
+    usize => [usize],
+    f32 => [f32, f64],
+    f64 => [f64],
+}
+
+pub fn has_from(from: &str, to: &str) -> bool {
+    LOSSLESS.contains(&(from, to))
+}
+
+/// One target type in the side by side comparison.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionRow {
+    pub to: &'static str,
+    /// What `value as to` gives, which never fails but may truncate, wrap or saturate.
+    pub as_cast: String,
+    pub checked: Result<String, ConversionError>,
+    /// Whether the standard library has `From`, so every value converts.
+    pub from_impl: bool,
+}
+
+macro_rules! row {
+    ($value:expr, $src:ty, $dst:ty) => {
+        ConversionRow {
+            to: stringify!($dst),
+            as_cast: ($value as $dst).to_text(),
+            checked: <$dst as SafeFrom<$src>>::safe_from($value).map(|converted| converted.to_text()),
+            from_impl: has_from(stringify!($src), stringify!($dst)),
+        }
+    };
+}
+
+macro_rules! rows {
+    ($value:expr, $type_name:expr, $src:ty) => {{
+        let value: $src = $value.replace('_', "").parse().map_err(|_| ConversionError::InvalidValue {
+            value: $value.to_string(),
+            type_name: $type_name.to_string(),
+        })?;
+
+        vec![
+            row!(value, $src, i8), row!(value, $src, i16), row!(value, $src, i32),
+            row!(value, $src, i64), row!(value, $src, i128), row!(value, $src, isize),
+            row!(value, $src, u8), row!(value, $src, u16), row!(value, $src, u32),
+            row!(value, $src, u64), row!(value, $src, u128), row!(value, $src, usize),
+            row!(value, $src, f32), row!(value, $src, f64),
+        ]
+    }};
+}
+
+/// Converts `value` of the named type into every numeric type, with `as` and with `SafeFrom`.
+#[allow(clippy::unnecessary_cast)]
+pub fn conversion_table(type_name: &str, value: &str) -> Result<Vec<ConversionRow>, ConversionError> {
+    let rows = match type_name {
+        "i8" => rows!(value, type_name, i8),
+        "i16" => rows!(value, type_name, i16),
+        "i32" => rows!(value, type_name, i32),
+        "i64" => rows!(value, type_name, i64),
+        "i128" => rows!(value, type_name, i128),
+        "isize" => rows!(value, type_name, isize),
+        "u8" => rows!(value, type_name, u8),
+        "u16" => rows!(value, type_name, u16),
+        "u32" => rows!(value, type_name, u32),
+        "u64" => rows!(value, type_name, u64),
+        "u128" => rows!(value, type_name, u128),
+        "usize" => rows!(value, type_name, usize),
+        "f32" => rows!(value, type_name, f32),
+        "f64" => rows!(value, type_name, f64),
+        _ => return Err(ConversionError::UnknownType(type_name.to_string())),
+    };
+
+    Ok(rows)
+}
+
+pub fn print_conversion_table(type_name: &str, value: &str) -> Result<(), ConversionError> {
+    let rows = conversion_table(type_name, value)?;
+
+    println!("{}: {}", type_name, value);
+    println!();
+
+    let as_width = rows.iter().map(|row| row.as_cast.len()).max().unwrap_or(0).max("as".len());
+    println!("{:<6} {:<5} {:<as_width$}  SafeFrom", "To", "From", "as");
+    for row in &rows {
+        let from_impl = if row.from_impl { "yes" } else { "" };
+        let checked = match &row.checked {
+            Ok(value) if *value == row.as_cast => value.clone(),
+            Ok(value) => format!("{} (as differs)", value),
+            Err(err) => format!("Err: {}", err),
+        };
+        println!("{:<6} {:<5} {:<as_width$}  {}", row.to, from_impl, row.as_cast, checked);
+    }
+
+    Ok(())
+}
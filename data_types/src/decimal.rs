@@ -0,0 +1,325 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use crate::operator::Operator;
+
+/// Fraction digits above this are rejected, so `10^scale` always fits in an i128
+/// with room left for comparing values.
+pub const MAX_SCALE: u32 = 28;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecimalError {
+    Parse(String),
+    Overflow,
+    DivisionByZero,
+    ScaleTooLarge(u32),
+}
+
+impl fmt::Display for DecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecimalError::Parse(text) => write!(f, "'{}' is not a decimal number like 5.50", text),
+            DecimalError::Overflow => write!(f, "the result does not fit in a Decimal"),
+            DecimalError::DivisionByZero => write!(f, "cannot divide by zero"),
+            DecimalError::ScaleTooLarge(scale) => write!(f, "{} fraction digits is more than the maximum of {}", scale, MAX_SCALE),
+        }
+    }
+}
+
+impl Error for DecimalError {}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to the nearest, ties to the even digit. Also called banker's rounding.
+    #[default]
+    HalfEven,
+    /// Round to the nearest, ties away from zero, the way it is taught in school.
+    HalfUp,
+    /// Drop the extra digits, towards zero.
+    Down,
+    /// Away from zero whenever digits are dropped.
+    Up,
+    /// Towards negative infinity.
+    Floor,
+    /// Towards positive infinity.
+    Ceiling,
+}
+
+impl FromStr for RoundingMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "half-even" => Ok(RoundingMode::HalfEven),
+            "half-up" => Ok(RoundingMode::HalfUp),
+            "down" => Ok(RoundingMode::Down),
+            "up" => Ok(RoundingMode::Up),
+            "floor" => Ok(RoundingMode::Floor),
+            "ceiling" => Ok(RoundingMode::Ceiling),
+            _ => Err(format!("'{}' is not a rounding mode, use half-even, half-up, down, up, floor or ceiling", s)),
+        }
+    }
+}
+
+fn pow10(exponent: u32) -> Result<i128, DecimalError> {
+    10i128.checked_pow(exponent).ok_or(DecimalError::Overflow)
+}
+
+/// `numerator / denominator` rounded to an integer with `mode`.
+fn divide_rounded(numerator: i128, denominator: i128, mode: RoundingMode) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder == 0 {
+        return quotient;
+    }
+
+    let negative = (numerator < 0) != (denominator < 0);
+    let away_from_zero = if negative { quotient - 1 } else { quotient + 1 };
+    // Compare twice the remainder with the denominator without overflowing.
+    let half = remainder.unsigned_abs().cmp(&(denominator.unsigned_abs() - remainder.unsigned_abs()));
+
+    let round_away = match mode {
+        RoundingMode::Down => false,
+        RoundingMode::Up => true,
+        RoundingMode::Floor => negative,
+        RoundingMode::Ceiling => !negative,
+        RoundingMode::HalfUp => half != Ordering::Less,
+        RoundingMode::HalfEven => half == Ordering::Greater || half == Ordering::Equal && quotient % 2 != 0,
+    };
+
+    if round_away {
+        away_from_zero
+    } else {
+        quotient
+    }
+}
+
+/// An exact base 10 number, `mantissa / 10^scale`. Unlike `f64` it stores `0.1` exactly
+/// and keeps trailing zeros, so `5.50` stays `5.50`.
+#[derive(Debug, Clone, Copy)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    pub const ZERO: Decimal = Decimal { mantissa: 0, scale: 0 };
+
+    pub fn new(mantissa: i128, scale: u32) -> Result<Decimal, DecimalError> {
+        if scale > MAX_SCALE {
+            return Err(DecimalError::ScaleTooLarge(scale));
+        }
+        Ok(Decimal { mantissa, scale })
+    }
+
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// The same value with more fraction digits, `1.5` to scale 3 is `1.500`.
+    fn widen(&self, scale: u32) -> Result<Decimal, DecimalError> {
+        let mantissa = self.mantissa.checked_mul(pow10(scale - self.scale)?).ok_or(DecimalError::Overflow)?;
+        Decimal::new(mantissa, scale)
+    }
+
+    /// Changes the number of fraction digits, rounding with `mode` when digits are dropped.
+    pub fn round(&self, scale: u32, mode: RoundingMode) -> Result<Decimal, DecimalError> {
+        if scale >= self.scale {
+            return self.widen(scale);
+        }
+        let mantissa = divide_rounded(self.mantissa, pow10(self.scale - scale)?, mode);
+        Decimal::new(mantissa, scale)
+    }
+
+    /// Exact, the result has the larger of the two scales.
+    pub fn checked_add(&self, other: &Decimal) -> Result<Decimal, DecimalError> {
+        let scale = self.scale.max(other.scale);
+        let mantissa = self.widen(scale)?.mantissa.checked_add(other.widen(scale)?.mantissa).ok_or(DecimalError::Overflow)?;
+        Decimal::new(mantissa, scale)
+    }
+
+    /// Exact, the result has the larger of the two scales.
+    pub fn checked_sub(&self, other: &Decimal) -> Result<Decimal, DecimalError> {
+        let scale = self.scale.max(other.scale);
+        let mantissa = self.widen(scale)?.mantissa.checked_sub(other.widen(scale)?.mantissa).ok_or(DecimalError::Overflow)?;
+        Decimal::new(mantissa, scale)
+    }
+
+    /// Exact, the result scale is the sum of the two scales. Above `MAX_SCALE` the
+    /// extra digits are rounded away with `RoundingMode::HalfEven`.
+    pub fn checked_mul(&self, other: &Decimal) -> Result<Decimal, DecimalError> {
+        let mantissa = self.mantissa.checked_mul(other.mantissa).ok_or(DecimalError::Overflow)?;
+        let scale = self.scale + other.scale;
+        if scale <= MAX_SCALE {
+            return Decimal::new(mantissa, scale);
+        }
+
+        let mantissa = divide_rounded(mantissa, pow10(scale - MAX_SCALE)?, RoundingMode::HalfEven);
+        Decimal::new(mantissa, MAX_SCALE)
+    }
+
+    /// Fails only for the smallest mantissa, `i128::MIN` has no positive counterpart.
+    pub fn checked_neg(&self) -> Result<Decimal, DecimalError> {
+        let mantissa = self.mantissa.checked_neg().ok_or(DecimalError::Overflow)?;
+        Decimal::new(mantissa, self.scale)
+    }
+
+    /// Division is rarely exact, so the caller picks the scale and how to round.
+    pub fn checked_div(&self, other: &Decimal, scale: u32, mode: RoundingMode) -> Result<Decimal, DecimalError> {
+        if other.mantissa == 0 {
+            return Err(DecimalError::DivisionByZero);
+        }
+        if scale > MAX_SCALE {
+            return Err(DecimalError::ScaleTooLarge(scale));
+        }
+
+        // (a / 10^sa) / (b / 10^sb) * 10^scale = a * 10^(sb + scale) / (b * 10^sa)
+        let numerator = self.mantissa.checked_mul(pow10(other.scale + scale)?).ok_or(DecimalError::Overflow)?;
+        let denominator = other.mantissa.checked_mul(pow10(self.scale)?).ok_or(DecimalError::Overflow)?;
+
+        Decimal::new(divide_rounded(numerator, denominator, mode), scale)
+    }
+
+    /// The nearest `f64`, for when exactness no longer matters.
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+
+    /// Integer and fraction parts, the fraction widened to `scale` digits.
+    fn parts(&self, scale: u32) -> (i128, i128) {
+        let unit = 10i128.pow(self.scale);
+        let fraction = self.mantissa % unit * 10i128.pow(scale - self.scale);
+        (self.mantissa / unit, fraction)
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Decimal) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares values, so `1.5 == 1.50`. Integer and fraction parts are compared
+/// separately, which cannot overflow for any scale up to `MAX_SCALE`.
+impl Ord for Decimal {
+    fn cmp(&self, other: &Decimal) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        self.parts(scale).cmp(&other.parts(scale))
+    }
+}
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        self.checked_neg().expect("Decimal negation overflowed")
+    }
+}
+
+/// Panics on overflow like the integer operators do in debug builds, use `checked_add` to handle it.
+impl Add for Decimal {
+    type Output = Decimal;
+
+    fn add(self, other: Decimal) -> Decimal {
+        self.checked_add(&other).expect("Decimal addition overflowed")
+    }
+}
+
+impl Sub for Decimal {
+    type Output = Decimal;
+
+    fn sub(self, other: Decimal) -> Decimal {
+        self.checked_sub(&other).expect("Decimal subtraction overflowed")
+    }
+}
+
+impl Mul for Decimal {
+    type Output = Decimal;
+
+    fn mul(self, other: Decimal) -> Decimal {
+        self.checked_mul(&other).expect("Decimal multiplication overflowed")
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = DecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DecimalError::Parse(s.to_string());
+
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        let digits = format!("{}{}", integer, fraction);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        let scale = fraction.len() as u32;
+        if scale > MAX_SCALE {
+            return Err(DecimalError::ScaleTooLarge(scale));
+        }
+
+        let magnitude: i128 = digits.parse().map_err(|_| DecimalError::Overflow)?;
+        Decimal::new(if negative { -magnitude } else { magnitude }, scale)
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let digits = format!("{:0width$}", self.mantissa.unsigned_abs(), width = self.scale as usize + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - self.scale as usize);
+
+        if fraction.is_empty() {
+            write!(f, "{}{}", sign, integer)
+        } else {
+            write!(f, "{}{}.{}", sign, integer, fraction)
+        }
+    }
+}
+
+/// Runs the same operation with `f64` and with `Decimal`, division rounded to `scale` digits.
+pub fn compare_with_f64(a: &str, operator: Operator, b: &str, scale: u32, mode: RoundingMode) -> Result<(f64, Decimal), DecimalError> {
+    let (x, y): (Decimal, Decimal) = (a.parse()?, b.parse()?);
+    let (float_x, float_y): (f64, f64) = (x.to_f64(), y.to_f64());
+
+    let result = match operator {
+        Operator::Add => (float_x + float_y, x.checked_add(&y)?),
+        Operator::Sub => (float_x - float_y, x.checked_sub(&y)?),
+        Operator::Mul => (float_x * float_y, x.checked_mul(&y)?),
+        Operator::Div => (float_x / float_y, x.checked_div(&y, scale, mode)?),
+    };
+
+    Ok(result)
+}
+
+pub fn print_comparison(a: &str, operator: Operator, b: &str, scale: u32, mode: RoundingMode) -> Result<(), DecimalError> {
+    let (float, decimal) = compare_with_f64(a, operator, b, scale, mode)?;
+
+    println!("f64     : {}", float);
+    println!("Decimal : {}", decimal);
+    if operator == Operator::Div {
+        println!("          rounded to {} digits with {:?}", scale, mode);
+    }
+
+    Ok(())
+}
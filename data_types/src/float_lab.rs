@@ -0,0 +1,139 @@
+/// How close two floats have to be to count as equal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// `|a - b| <= epsilon`, good near zero but meaningless for big values.
+    Absolute(f64),
+    /// `|a - b| <= epsilon * max(|a|, |b|)`, scales with the values but breaks down near zero.
+    Relative(f64),
+    /// At most this many representable floats between `a` and `b`.
+    Ulps(u64),
+}
+
+/// Maps the bits of a float onto a line where neighbouring floats are neighbouring
+/// integers, negative floats below positive ones and both zeros on 0.
+fn ordered_bits(value: f64) -> i64 {
+    let bits = value.to_bits() as i64;
+    if bits < 0 {
+        i64::MIN - bits
+    } else {
+        bits
+    }
+}
+
+/// Number of representable `f64` steps from `a` to `b`, `None` if either is NaN.
+pub fn ulps_between(a: f64, b: f64) -> Option<u64> {
+    if a.is_nan() || b.is_nan() {
+        return None;
+    }
+    Some(ordered_bits(a).abs_diff(ordered_bits(b)))
+}
+
+/// NaN is never approximately equal to anything, and infinities only to themselves.
+pub fn approx_eq(a: f64, b: f64, tolerance: Tolerance) -> bool {
+    if a.is_nan() || b.is_nan() {
+        return false;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return a == b;
+    }
+
+    match tolerance {
+        Tolerance::Absolute(epsilon) => (a - b).abs() <= epsilon,
+        Tolerance::Relative(epsilon) => (a - b).abs() <= epsilon * a.abs().max(b.abs()),
+        Tolerance::Ulps(max) => ulps_between(a, b).is_some_and(|ulps| ulps <= max),
+    }
+}
+
+/// An expression and the value it produced, for printing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Example {
+    pub expression: &'static str,
+    pub value: f64,
+}
+
+fn example(expression: &'static str, value: f64) -> Example {
+    Example { expression, value }
+}
+
+// Equal operands are the point here, `x - x` and `x / x` are where NaN comes from.
+#[allow(clippy::eq_op)]
+pub fn special_values() -> Vec<Example> {
+    let zero = 0.0_f64;
+    let one = 1.0_f64;
+
+    vec![
+        example("0.0 / 0.0", zero / zero),
+        example("f64::INFINITY - f64::INFINITY", f64::INFINITY - f64::INFINITY),
+        example("(-1.0_f64).sqrt()", (-one).sqrt()),
+        example("1.0 / 0.0", one / zero),
+        example("-1.0 / 0.0", -one / zero),
+        example("f64::MAX * 2.0", f64::MAX * 2.0),
+        example("-0.0", -zero),
+        example("1.0 / f64::NEG_INFINITY", one / f64::NEG_INFINITY),
+        example("f64::MIN_POSITIVE", f64::MIN_POSITIVE),
+        example("f64::MIN_POSITIVE / 2.0", f64::MIN_POSITIVE / 2.0),
+        example("f64::from_bits(1)", f64::from_bits(1)),
+        example("f64::EPSILON", f64::EPSILON),
+        example("1.0 + f64::EPSILON", one + f64::EPSILON),
+        example("1.0 + f64::EPSILON / 2.0", one + f64::EPSILON / 2.0),
+    ]
+}
+
+fn kind(value: f64) -> &'static str {
+    if value.is_nan() {
+        "NaN"
+    } else if value.is_infinite() {
+        "infinite"
+    } else if value == 0.0 && value.is_sign_negative() {
+        "negative zero"
+    } else if value == 0.0 {
+        "zero"
+    } else if value.is_subnormal() {
+        "subnormal"
+    } else {
+        "normal"
+    }
+}
+
+/// `sort_by(f64::total_cmp)` works where `sort()` does not compile, because
+/// `f64` is only `PartialOrd`.
+pub fn sorted_with_total_cmp(values: &[f64]) -> Vec<f64> {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+#[allow(clippy::eq_op)]
+pub fn print_float_lab() {
+    println!("Special values");
+    for example in special_values() {
+        println!("    {:<32} = {:<24?} {}", example.expression, example.value, kind(example.value));
+    }
+
+    let nan = f64::NAN;
+    println!();
+    println!("Comparisons");
+    println!("    {:<32} = {}", "NAN == NAN", nan == nan);
+    println!("    {:<32} = {:?}", "NAN.partial_cmp(&NAN)", nan.partial_cmp(&nan));
+    println!("    {:<32} = {:?}", "NAN.total_cmp(&NAN)", nan.total_cmp(&nan));
+    println!("    {:<32} = {}", "-0.0 == 0.0", -0.0_f64 == 0.0);
+    println!("    {:<32} = {:?}", "(-0.0).total_cmp(&0.0)", (-0.0_f64).total_cmp(&0.0));
+    println!("    {:<32} = {}", "0.1 + 0.2 == 0.3", 0.1 + 0.2 == 0.3);
+
+    let values = [3.0, f64::NAN, -0.0, f64::NEG_INFINITY, 0.0, -f64::NAN, f64::INFINITY, -1.5];
+    println!();
+    println!("Sorting {:?}", values);
+    println!("    with total_cmp: {:?}", sorted_with_total_cmp(&values));
+
+    let (a, b) = (0.1 + 0.2, 0.3);
+    println!();
+    println!("Approximate equality of 0.1 + 0.2 and 0.3, {} ULPs apart", ulps_between(a, b).unwrap_or(0));
+    for tolerance in [
+        Tolerance::Absolute(1e-12),
+        Tolerance::Relative(f64::EPSILON),
+        Tolerance::Ulps(1),
+        Tolerance::Ulps(0),
+    ] {
+        println!("    {:<32} = {}", format!("{:?}", tolerance), approx_eq(a, b, tolerance));
+    }
+}
@@ -0,0 +1,67 @@
+use std::mem::{align_of, size_of};
+
+/// Size, alignment and range of a type, all read from the compiler rather than written down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    pub type_name: &'static str,
+    pub size: usize,
+    pub align: usize,
+    pub bits: u32,
+    /// `None` for compound types, which have no single range.
+    pub range: Option<(String, String)>,
+}
+
+fn layout_of<T>(type_name: &'static str, range: Option<(String, String)>) -> Layout {
+    Layout {
+        type_name,
+        size: size_of::<T>(),
+        align: align_of::<T>(),
+        bits: (size_of::<T>() * 8) as u32,
+        range,
+    }
+}
+
+macro_rules! scalar_layouts {
+    ($format:literal; $($t:ty),*) => {
+        [$(layout_of::<$t>(stringify!($t), Some((format!($format, <$t>::MIN), format!($format, <$t>::MAX))))),*]
+    };
+}
+
+/// Every scalar type, then the tuples and arrays used in the lessons.
+pub fn layouts() -> Vec<Layout> {
+    let mut layouts = Vec::new();
+    layouts.extend(scalar_layouts!("{}"; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize));
+    // The float limits are over 300 digits long when written out in full.
+    layouts.extend(scalar_layouts!("{:e}"; f32, f64));
+
+    layouts.push(layout_of::<bool>("bool", Some((false.to_string(), true.to_string()))));
+    layouts.push(layout_of::<char>("char", Some((format!("U+{:04X}", char::MIN as u32), format!("U+{:04X}", char::MAX as u32)))));
+
+    layouts.push(layout_of::<()>("()", None));
+    layouts.push(layout_of::<(i32, f64, i32)>("(i32, f64, i32)", None));
+    layouts.push(layout_of::<(u8, bool)>("(u8, bool)", None));
+    layouts.push(layout_of::<[i32; 5]>("[i32; 5]", None));
+
+    layouts
+}
+
+pub fn print_layouts() {
+    let layouts = layouts();
+    let name_width = layouts.iter().map(|layout| layout.type_name.len()).max().unwrap_or(0);
+
+    println!("{:<name_width$}  {:>4}  {:>5}  {:>4}  range", "type", "size", "align", "bits");
+    for layout in &layouts {
+        let range = match &layout.range {
+            Some((min, max)) => format!("{} ..= {}", min, max),
+            None => String::new(),
+        };
+        let line = format!(
+            "{:<name_width$}  {:>4}  {:>5}  {:>4}  {}",
+            layout.type_name, layout.size, layout.align, layout.bits, range
+        );
+        println!("{}", line.trim_end());
+    }
+
+    println!();
+    println!("isize and usize are {} bits on this {} machine.", usize::BITS, std::env::consts::ARCH);
+}
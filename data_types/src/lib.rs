@@ -0,0 +1,10 @@
+pub mod arithmetic;
+pub mod cli;
+pub mod conversion;
+pub mod decimal;
+pub mod float_lab;
+pub mod layout;
+pub mod literal;
+pub mod operator;
+pub mod precision;
+pub mod rational;
@@ -0,0 +1,323 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::arithmetic::TYPE_NAMES;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LiteralError {
+    Empty,
+    /// A prefix or sign with no digits after it, like `0x` or `-`.
+    NoDigits(String),
+    InvalidDigit { digit: char, radix: u32 },
+    UnknownSuffix(String),
+    OutOfRange { literal: String, type_name: &'static str },
+    /// `-` in front of a `u8`..`usize` literal.
+    NegativeUnsigned(String),
+    InvalidByte(String),
+}
+
+impl fmt::Display for LiteralError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LiteralError::Empty => write!(f, "the literal is empty"),
+            LiteralError::NoDigits(literal) => write!(f, "'{}' has no digits", literal),
+            LiteralError::InvalidDigit { digit, radix } => write!(f, "'{}' is not a base {} digit", digit, radix),
+            LiteralError::UnknownSuffix(suffix) => {
+                write!(f, "'{}' is not an integer suffix, use one of {}", suffix, TYPE_NAMES.join(", "))
+            },
+            LiteralError::OutOfRange { literal, type_name } => write!(f, "{} does not fit in {}", literal, type_name),
+            LiteralError::NegativeUnsigned(literal) => write!(f, "{} is negative but the type is unsigned", literal),
+            LiteralError::InvalidByte(literal) => write!(f, "{} is not an ASCII byte literal", literal),
+        }
+    }
+}
+
+impl Error for LiteralError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Radix {
+    Binary,
+    Octal,
+    Decimal,
+    Hex,
+}
+
+impl Radix {
+    pub const ALL: [Radix; 4] = [Radix::Decimal, Radix::Hex, Radix::Octal, Radix::Binary];
+
+    pub fn base(&self) -> u32 {
+        match self {
+            Radix::Binary => 2,
+            Radix::Octal => 8,
+            Radix::Decimal => 10,
+            Radix::Hex => 16,
+        }
+    }
+
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Radix::Binary => "0b",
+            Radix::Octal => "0o",
+            Radix::Decimal => "",
+            Radix::Hex => "0x",
+        }
+    }
+
+    /// Decimal reads best in thousands, the others in groups of bits.
+    pub fn group_size(&self) -> usize {
+        match self {
+            Radix::Decimal | Radix::Octal => 3,
+            Radix::Binary | Radix::Hex => 4,
+        }
+    }
+}
+
+/// A parsed literal, typed by its suffix or `i32` when it has none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntValue {
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    Isize(isize),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+}
+
+impl IntValue {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            IntValue::I8(_) => "i8",
+            IntValue::I16(_) => "i16",
+            IntValue::I32(_) => "i32",
+            IntValue::I64(_) => "i64",
+            IntValue::I128(_) => "i128",
+            IntValue::Isize(_) => "isize",
+            IntValue::U8(_) => "u8",
+            IntValue::U16(_) => "u16",
+            IntValue::U32(_) => "u32",
+            IntValue::U64(_) => "u64",
+            IntValue::U128(_) => "u128",
+            IntValue::Usize(_) => "usize",
+        }
+    }
+
+    /// The sign and absolute value, wide enough for every type including `i128::MIN`.
+    pub fn sign_and_magnitude(&self) -> (bool, u128) {
+        let signed = |value: i128| (value < 0, value.unsigned_abs());
+        match *self {
+            IntValue::I8(value) => signed(value as i128),
+            IntValue::I16(value) => signed(value as i128),
+            IntValue::I32(value) => signed(value as i128),
+            IntValue::I64(value) => signed(value as i128),
+            IntValue::I128(value) => signed(value),
+            IntValue::Isize(value) => signed(value as i128),
+            IntValue::U8(value) => (false, value as u128),
+            IntValue::U16(value) => (false, value as u128),
+            IntValue::U32(value) => (false, value as u128),
+            IntValue::U64(value) => (false, value as u128),
+            IntValue::U128(value) => (false, value),
+            IntValue::Usize(value) => (false, value as u128),
+        }
+    }
+
+    /// Formats the value as a Rust literal in `radix`, with `_` between digit groups when `grouped`.
+    pub fn format(&self, radix: Radix, grouped: bool) -> String {
+        let (negative, magnitude) = self.sign_and_magnitude();
+        format_int(negative, magnitude, radix, grouped)
+    }
+}
+
+impl fmt::Display for IntValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.format(Radix::Decimal, false), self.type_name())
+    }
+}
+
+pub fn format_int(negative: bool, magnitude: u128, radix: Radix, grouped: bool) -> String {
+    let digits = match radix {
+        Radix::Binary => format!("{:b}", magnitude),
+        Radix::Octal => format!("{:o}", magnitude),
+        Radix::Decimal => magnitude.to_string(),
+        Radix::Hex => format!("{:x}", magnitude),
+    };
+
+    let digits = if grouped { group_digits(&digits, radix.group_size()) } else { digits };
+    let sign = if negative { "-" } else { "" };
+
+    format!("{}{}{}", sign, radix.prefix(), digits)
+}
+
+fn group_digits(digits: &str, size: usize) -> String {
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(size) {
+            grouped.push('_');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+fn parse_byte(literal: &str) -> Result<u8, LiteralError> {
+    let invalid = || LiteralError::InvalidByte(literal.to_string());
+    let inner = literal
+        .strip_prefix("b'")
+        .and_then(|rest| rest.strip_suffix('\''))
+        .ok_or_else(invalid)?;
+
+    let byte = match inner.as_bytes() {
+        [b'\\', b'n'] => b'\n',
+        [b'\\', b'r'] => b'\r',
+        [b'\\', b't'] => b'\t',
+        [b'\\', b'0'] => b'\0',
+        [b'\\', b'\\'] => b'\\',
+        [b'\\', b'\''] => b'\'',
+        [b'\\', b'"'] => b'"',
+        [b'\\', b'x', high, low] => {
+            let hex = std::str::from_utf8(&[*high, *low]).map_err(|_| invalid())?.to_string();
+            u8::from_str_radix(&hex, 16).map_err(|_| invalid())?
+        },
+        [byte] if byte.is_ascii() && *byte != b'\\' && *byte != b'\'' => *byte,
+        _ => return Err(invalid()),
+    };
+
+    Ok(byte)
+}
+
+fn split_suffix(literal: &str, radix: Radix) -> (&str, Option<&str>) {
+    // `e`, `a`..`f` are hex digits, so only look for a suffix starting with `i` or `u`.
+    let start = match radix {
+        Radix::Hex => literal.find(['i', 'u']),
+        _ => literal.find(|c: char| c.is_ascii_alphabetic()),
+    };
+
+    match start {
+        Some(start) => (&literal[..start], Some(&literal[start..])),
+        None => (literal, None),
+    }
+}
+
+fn parse_magnitude(digits: &str, radix: Radix, literal: &str, type_name: &'static str) -> Result<u128, LiteralError> {
+    let base = radix.base();
+    let mut magnitude: u128 = 0;
+    let mut seen_digit = false;
+
+    for c in digits.chars() {
+        if c == '_' {
+            continue;
+        }
+        let digit = c.to_digit(base).ok_or(LiteralError::InvalidDigit { digit: c, radix: base })?;
+        seen_digit = true;
+        magnitude = magnitude
+            .checked_mul(base as u128)
+            .and_then(|value| value.checked_add(digit as u128))
+            .ok_or_else(|| LiteralError::OutOfRange { literal: literal.to_string(), type_name })?;
+    }
+
+    if !seen_digit {
+        return Err(LiteralError::NoDigits(literal.to_string()));
+    }
+
+    Ok(magnitude)
+}
+
+macro_rules! typed_value {
+    ($negative:expr, $magnitude:expr, $literal:expr, $type_name:expr, $($name:literal => $variant:ident($t:ty)),*) => {
+        match $type_name {
+            $(
+                $name => {
+                    let out_of_range = || LiteralError::OutOfRange { literal: $literal.to_string(), type_name: $name };
+                    let value = if $negative {
+                        // i128::MIN has no positive counterpart, so it cannot be negated from one.
+                        let value = if $magnitude == i128::MIN.unsigned_abs() {
+                            i128::MIN
+                        } else {
+                            -i128::try_from($magnitude).map_err(|_| out_of_range())?
+                        };
+                        <$t>::try_from(value).map_err(|_| out_of_range())?
+                    } else {
+                        <$t>::try_from($magnitude).map_err(|_| out_of_range())?
+                    };
+                    Ok(IntValue::$variant(value))
+                }
+            )*
+            suffix => Err(LiteralError::UnknownSuffix(suffix.to_string())),
+        }
+    };
+}
+
+/// Parses a Rust integer literal: decimal with `_` separators, `0x`, `0o`, `0b`,
+/// `b'A'` byte literals and an optional type suffix like `58u8`. A leading `-`
+/// is accepted for signed types.
+pub fn parse_literal(literal: &str) -> Result<IntValue, LiteralError> {
+    let literal = literal.trim();
+    if literal.is_empty() {
+        return Err(LiteralError::Empty);
+    }
+
+    if literal.starts_with("b'") {
+        return parse_byte(literal).map(IntValue::U8);
+    }
+
+    let (negative, unsigned) = match literal.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, literal),
+    };
+
+    let (radix, body) = if let Some(rest) = unsigned.strip_prefix("0x") {
+        (Radix::Hex, rest)
+    } else if let Some(rest) = unsigned.strip_prefix("0o") {
+        (Radix::Octal, rest)
+    } else if let Some(rest) = unsigned.strip_prefix("0b") {
+        (Radix::Binary, rest)
+    } else {
+        (Radix::Decimal, unsigned)
+    };
+
+    if radix == Radix::Decimal && body.starts_with('_') {
+        return Err(LiteralError::InvalidDigit { digit: '_', radix: 10 });
+    }
+
+    let (digits, suffix) = split_suffix(body, radix);
+    let suffix = suffix.map(|suffix| suffix.trim_start_matches('_'));
+    let type_name: &'static str = match suffix {
+        None => "i32",
+        Some(suffix) => TYPE_NAMES
+            .iter()
+            .find(|name| **name == suffix)
+            .ok_or_else(|| LiteralError::UnknownSuffix(suffix.to_string()))?,
+    };
+
+    if negative && type_name.starts_with('u') {
+        return Err(LiteralError::NegativeUnsigned(literal.to_string()));
+    }
+
+    let magnitude = parse_magnitude(digits, radix, literal, type_name)?;
+
+    typed_value!(negative, magnitude, literal, type_name,
+        "i8" => I8(i8), "i16" => I16(i16), "i32" => I32(i32), "i64" => I64(i64),
+        "i128" => I128(i128), "isize" => Isize(isize),
+        "u8" => U8(u8), "u16" => U16(u16), "u32" => U32(u32), "u64" => U64(u64),
+        "u128" => U128(u128), "usize" => Usize(usize)
+    )
+}
+
+pub fn print_literal(literal: &str) -> Result<(), LiteralError> {
+    let value = parse_literal(literal)?;
+
+    println!("{} is {}", literal, value);
+    println!();
+
+    let width = value.format(Radix::Binary, false).len();
+    for radix in Radix::ALL {
+        println!("{:<8} {:<width$}  {}", format!("{:?}", radix), value.format(radix, false), value.format(radix, true));
+    }
+
+    Ok(())
+}
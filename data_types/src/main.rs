@@ -1,3 +1,11 @@
+use std::env;
+use std::process;
+
+use data_types::cli::{self, Command};
+use data_types::{arithmetic, conversion, decimal, float_lab, layout, literal, precision, rational};
+
+// The extra digits are the point of the lesson, they show what f32 and f64 drop.
+#[allow(clippy::excessive_precision)]
 fn floating_type() {
     let my_f32 : f32 = 21.321654651651651;
     let my_f64 : f64  = 21.21354651654165165416;
@@ -6,6 +14,8 @@ fn floating_type() {
     println!("My F64 : {}", my_f64);
 }
 
+// The lesson is about `as`, so the casts stay even though a float literal would do.
+#[allow(clippy::unnecessary_cast)]
 fn numeric_operation() {
     let sum = 5 + 5;
 
@@ -19,6 +29,44 @@ fn numeric_operation() {
 }
 
 fn main() {
-    floating_type();
-    numeric_operation();
+    let command = match cli::parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("Error: {}\n\n{}", err, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    let result: Result<(), Box<dyn std::error::Error>> = match command {
+        Command::Lessons => {
+            floating_type();
+            numeric_operation();
+            Ok(())
+        },
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        },
+        Command::Precision(literal) => precision::print_precision_report(&literal).map_err(Into::into),
+        Command::Arithmetic { type_name, a, b } => arithmetic::print_operation_table(&type_name, &a, &b).map_err(Into::into),
+        Command::Convert { type_name, value } => conversion::print_conversion_table(&type_name, &value).map_err(Into::into),
+        Command::Literal(text) => literal::print_literal(&text).map_err(Into::into),
+        Command::Decimal { a, operator, b, scale, rounding } => {
+            decimal::print_comparison(&a, operator, &b, scale, rounding).map_err(Into::into)
+        },
+        Command::Rational { a, operator, b } => rational::print_comparison(&a, operator, &b).map_err(Into::into),
+        Command::Floats => {
+            float_lab::print_float_lab();
+            Ok(())
+        },
+        Command::Layout => {
+            layout::print_layouts();
+            Ok(())
+        },
+    };
+
+    if let Err(err) = result {
+        eprintln!("Error: {}", err);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,24 @@
+use std::str::FromStr;
+
+/// The arithmetic operator of the `decimal` and `rational` subcommands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl FromStr for Operator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Operator::Add),
+            "-" => Ok(Operator::Sub),
+            "*" | "x" => Ok(Operator::Mul),
+            "/" => Ok(Operator::Div),
+            _ => Err(format!("'{}' is not an operator, use + - * or /", s)),
+        }
+    }
+}
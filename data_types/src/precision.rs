@@ -0,0 +1,267 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidLiteral(pub String);
+
+impl fmt::Display for InvalidLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "'{}' is not a decimal literal like 21.321654651651651", self.0)
+    }
+}
+
+impl Error for InvalidLiteral {}
+
+/// How a decimal literal ends up stored in one floating-point type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FloatReport {
+    pub type_name: &'static str,
+    /// The exact decimal value of the stored bits.
+    pub stored: String,
+    /// `stored - literal`, `0` when the literal is stored exactly.
+    pub error: f64,
+    /// How many significant digits the stored value is correct to, `None` if it was stored exactly.
+    pub significant_digits: Option<usize>,
+    pub sign: u64,
+    pub exponent_bits: String,
+    /// The exponent with the bias removed.
+    pub exponent: i32,
+    pub mantissa_bits: String,
+}
+
+/// A decimal number as plain digits, `integer.fraction`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Digits {
+    negative: bool,
+    integer: Vec<u8>,
+    fraction: Vec<u8>,
+}
+
+impl Digits {
+    fn parse(literal: &str) -> Option<Digits> {
+        let literal = literal.replace('_', "");
+        let (negative, unsigned) = match literal.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, literal.strip_prefix('+').unwrap_or(&literal)),
+        };
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if integer.is_empty() && fraction.is_empty() || !all_digits(integer) || !all_digits(fraction) {
+            return None;
+        }
+
+        let mut digits = Digits {
+            negative,
+            integer: integer.bytes().map(|b| b - b'0').collect(),
+            fraction: fraction.bytes().map(|b| b - b'0').collect(),
+        };
+        digits.trim();
+        Some(digits)
+    }
+
+    /// Every finite binary float has a finite decimal expansion, and formatting
+    /// with enough fraction digits prints all of it.
+    fn exact(value: f64) -> Digits {
+        let biased = ((value.to_bits() >> 52) & 0x7ff) as i32;
+        let exponent = if biased == 0 { -1022 } else { biased - 1023 };
+        let precision = (52 - exponent).max(0) as usize;
+
+        let mut digits = Digits::parse(&format!("{:.*}", precision, value.abs())).expect("formatted float is a decimal");
+        digits.negative = value.is_sign_negative();
+        digits
+    }
+
+    fn trim(&mut self) {
+        while self.integer.len() > 1 && self.integer[0] == 0 {
+            self.integer.remove(0);
+        }
+        if self.integer.is_empty() {
+            self.integer.push(0);
+        }
+        while self.fraction.last() == Some(&0) {
+            self.fraction.pop();
+        }
+    }
+
+    /// Both numbers as digit strings with the decimal point in the same place.
+    fn aligned(&self, other: &Digits) -> (Vec<u8>, Vec<u8>, usize) {
+        let integer_len = self.integer.len().max(other.integer.len());
+        let fraction_len = self.fraction.len().max(other.fraction.len());
+
+        let pad = |digits: &Digits| {
+            let mut padded = vec![0; integer_len - digits.integer.len()];
+            padded.extend(&digits.integer);
+            padded.extend(&digits.fraction);
+            padded.resize(integer_len + fraction_len, 0);
+            padded
+        };
+
+        (pad(self), pad(other), integer_len)
+    }
+
+    fn to_string_lossy(&self) -> String {
+        let integer: String = self.integer.iter().map(|d| (b'0' + d) as char).collect();
+        let fraction: String = self.fraction.iter().map(|d| (b'0' + d) as char).collect();
+        let sign = if self.negative { "-" } else { "" };
+
+        if fraction.is_empty() {
+            format!("{}{}", sign, integer)
+        } else {
+            format!("{}{}.{}", sign, integer, fraction)
+        }
+    }
+}
+
+fn subtract_magnitudes(larger: &[u8], smaller: &[u8]) -> Vec<u8> {
+    let mut result = vec![0; larger.len()];
+    let mut borrow = 0;
+    for i in (0..larger.len()).rev() {
+        let mut digit = larger[i] as i8 - smaller[i] as i8 - borrow;
+        borrow = if digit < 0 { 1 } else { 0 };
+        if digit < 0 {
+            digit += 10;
+        }
+        result[i] = digit as u8;
+    }
+    result
+}
+
+fn add_magnitudes(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut result = vec![0; a.len() + 1];
+    let mut carry = 0;
+    for i in (0..a.len()).rev() {
+        let digit = a[i] + b[i] + carry;
+        result[i + 1] = digit % 10;
+        carry = digit / 10;
+    }
+    result[0] = carry;
+    result
+}
+
+/// `stored - literal`, exactly.
+fn difference(stored: &Digits, literal: &Digits) -> Digits {
+    let (a, b, integer_len) = stored.aligned(literal);
+
+    let (magnitude, integer_len, negative) = if stored.negative == literal.negative {
+        match a.cmp(&b) {
+            Ordering::Less => (subtract_magnitudes(&b, &a), integer_len, !stored.negative),
+            _ => (subtract_magnitudes(&a, &b), integer_len, stored.negative),
+        }
+    } else {
+        (add_magnitudes(&a, &b), integer_len + 1, stored.negative)
+    };
+
+    let mut digits = Digits {
+        negative,
+        integer: magnitude[..integer_len].to_vec(),
+        fraction: magnitude[integer_len..].to_vec(),
+    };
+    digits.trim();
+    digits
+}
+
+/// Rounded to an `f64` only at the very end.
+fn to_f64(digits: &Digits) -> f64 {
+    // Parsing a float string rounds correctly, however many digits it has.
+    let value: f64 = digits.to_string_lossy().parse().unwrap_or(f64::NAN);
+    if value == 0.0 {
+        0.0
+    } else {
+        value
+    }
+}
+
+/// The power of ten of the first non-zero digit and the digits from there on, `None` for zero.
+fn leading_digits(digits: &Digits) -> Option<(i32, Vec<u8>)> {
+    let all: Vec<u8> = digits.integer.iter().chain(&digits.fraction).copied().collect();
+    let first = all.iter().position(|&digit| digit != 0)?;
+    Some((digits.integer.len() as i32 - 1 - first as i32, all[first..].to_vec()))
+}
+
+/// The largest `k` with `|error| <= 0.5 * 10^(e - k + 1)`, where `10^e` is the place of the
+/// literal's first digit, so the stored value rounds back to the literal at `k` digits.
+fn significant_digits(error: &Digits, literal: &Digits) -> Option<usize> {
+    let (error_power, error_digits) = leading_digits(error)?;
+    let (literal_power, _) = leading_digits(literal)?;
+
+    // With the error written as `m * 10^d`, the bound holds for `k = e - d` when `m <= 5`.
+    let at_most_half = error_digits[0] < 5 || error_digits[0] == 5 && error_digits[1..].iter().all(|&digit| digit == 0);
+    let digits = literal_power - error_power - if at_most_half { 0 } else { 1 };
+
+    Some(digits.max(0) as usize)
+}
+
+fn bits(value: u64, width: u32) -> String {
+    format!("{:0width$b}", value, width = width as usize)
+}
+
+fn build_report(type_name: &'static str, value: f64, raw: u64, exponent_width: u32, mantissa_width: u32, literal: &Digits) -> FloatReport {
+    let exponent_mask = (1u64 << exponent_width) - 1;
+    let biased = (raw >> mantissa_width) & exponent_mask;
+    let bias = (exponent_mask >> 1) as i32;
+    let exponent = if biased == 0 { 1 - bias } else { biased as i32 - bias };
+
+    let (stored, error, significant_digits) = if value.is_finite() {
+        let stored = Digits::exact(value);
+        let error = difference(&stored, literal);
+        (stored.to_string_lossy(), to_f64(&error), significant_digits(&error, literal))
+    } else {
+        (value.to_string(), f64::INFINITY, Some(0))
+    };
+
+    FloatReport {
+        type_name,
+        stored,
+        error,
+        significant_digits,
+        sign: raw >> (exponent_width + mantissa_width),
+        exponent_bits: bits(biased, exponent_width),
+        exponent,
+        mantissa_bits: bits(raw & ((1u64 << mantissa_width) - 1), mantissa_width),
+    }
+}
+
+pub fn precision_report(literal: &str) -> Result<[FloatReport; 2], InvalidLiteral> {
+    let digits = Digits::parse(literal).ok_or_else(|| InvalidLiteral(literal.to_string()))?;
+    let cleaned = digits.to_string_lossy();
+
+    let as_f32: f32 = cleaned.parse().map_err(|_| InvalidLiteral(literal.to_string()))?;
+    let as_f64: f64 = cleaned.parse().map_err(|_| InvalidLiteral(literal.to_string()))?;
+
+    Ok([
+        build_report("f32", as_f32 as f64, as_f32.to_bits() as u64, 8, 23, &digits),
+        build_report("f64", as_f64, as_f64.to_bits(), 11, 52, &digits),
+    ])
+}
+
+pub fn print_precision_report(literal: &str) -> Result<(), InvalidLiteral> {
+    let reports = precision_report(literal)?;
+
+    println!("Literal : {}", literal);
+    println!();
+    println!("{:<5} {:<12} {:<10} Stored value", "Type", "Error", "Sig digits");
+    for report in &reports {
+        let digits = match report.significant_digits {
+            Some(digits) => digits.to_string(),
+            None => String::from("exact"),
+        };
+        let error = if report.error == 0.0 { String::from("0") } else { format!("{:.3e}", report.error) };
+        println!("{:<5} {:<12} {:<10} {}", report.type_name, error, digits, report.stored);
+    }
+
+    println!();
+    println!("{:<5} {:<4} {:<18} Mantissa", "Type", "Sign", "Exponent");
+    for report in &reports {
+        println!(
+            "{:<5} {:<4} {:<18} {}",
+            report.type_name,
+            report.sign,
+            format!("{} ({})", report.exponent_bits, report.exponent),
+            report.mantissa_bits
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,258 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use crate::operator::Operator;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RationalError {
+    Parse(String),
+    ZeroDenominator,
+    DivisionByZero,
+    Overflow,
+}
+
+impl fmt::Display for RationalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RationalError::Parse(text) => write!(f, "'{}' is not a fraction like 5/2 or a whole number", text),
+            RationalError::ZeroDenominator => write!(f, "the denominator cannot be zero"),
+            RationalError::DivisionByZero => write!(f, "cannot divide by zero"),
+            RationalError::Overflow => write!(f, "the result does not fit in an i64 fraction"),
+        }
+    }
+}
+
+impl Error for RationalError {}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// An exact fraction. It is always stored in lowest terms with a positive
+/// denominator, so `2/-4` becomes `-1/2` and equal values have equal fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numerator: 0, denominator: 1 };
+    pub const ONE: Rational = Rational { numerator: 1, denominator: 1 };
+
+    pub fn new(numerator: i64, denominator: i64) -> Result<Rational, RationalError> {
+        Rational::reduce(numerator as i128, denominator as i128)
+    }
+
+    pub fn from_integer(value: i64) -> Rational {
+        Rational { numerator: value, denominator: 1 }
+    }
+
+    /// Works in i128 so the intermediate products of two i64 fractions cannot
+    /// overflow, only the reduced result has to fit back into i64.
+    fn reduce(numerator: i128, denominator: i128) -> Result<Rational, RationalError> {
+        if denominator == 0 {
+            return Err(RationalError::ZeroDenominator);
+        }
+
+        let divisor = gcd(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128;
+        let sign = denominator.signum();
+        let numerator = numerator / divisor * sign;
+        let denominator = denominator / divisor * sign;
+
+        Ok(Rational {
+            numerator: i64::try_from(numerator).map_err(|_| RationalError::Overflow)?,
+            denominator: i64::try_from(denominator).map_err(|_| RationalError::Overflow)?,
+        })
+    }
+
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn checked_add(&self, other: &Rational) -> Result<Rational, RationalError> {
+        let (a, b, c, d) = self.widen(other);
+        // a/b + c/d fits in i128 because every factor fits in i64.
+        Rational::reduce(a * d + c * b, b * d)
+    }
+
+    pub fn checked_sub(&self, other: &Rational) -> Result<Rational, RationalError> {
+        let (a, b, c, d) = self.widen(other);
+        Rational::reduce(a * d - c * b, b * d)
+    }
+
+    pub fn checked_mul(&self, other: &Rational) -> Result<Rational, RationalError> {
+        let (a, b, c, d) = self.widen(other);
+        Rational::reduce(a * c, b * d)
+    }
+
+    pub fn checked_div(&self, other: &Rational) -> Result<Rational, RationalError> {
+        if other.numerator == 0 {
+            return Err(RationalError::DivisionByZero);
+        }
+        let (a, b, c, d) = self.widen(other);
+        Rational::reduce(a * d, b * c)
+    }
+
+    pub fn checked_neg(&self) -> Result<Rational, RationalError> {
+        Rational::reduce(-(self.numerator as i128), self.denominator as i128)
+    }
+
+    pub fn recip(&self) -> Result<Rational, RationalError> {
+        if self.numerator == 0 {
+            return Err(RationalError::DivisionByZero);
+        }
+        Rational::reduce(self.denominator as i128, self.numerator as i128)
+    }
+
+    fn widen(&self, other: &Rational) -> (i128, i128, i128, i128) {
+        (self.numerator as i128, self.denominator as i128, other.numerator as i128, other.denominator as i128)
+    }
+
+    /// Only here does the value become inexact.
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    pub fn to_f32(&self) -> f32 {
+        self.to_f64() as f32
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::ZERO
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational::from_integer(value)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Cross multiplies, the denominators are positive so the order is preserved.
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        let (a, b, c, d) = self.widen(other);
+        (a * d).cmp(&(c * b))
+    }
+}
+
+/// A fraction has no infinity or NaN to fall back on, so these panic when the reduced
+/// result does not fit in i64 or the divisor is zero. The `checked_` methods return the error.
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        self.checked_add(&other).expect("Rational addition overflowed")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self.checked_sub(&other).expect("Rational subtraction overflowed")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        self.checked_mul(&other).expect("Rational multiplication overflowed")
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Rational {
+        match self.checked_div(&other) {
+            Ok(result) => result,
+            Err(RationalError::DivisionByZero) => panic!("attempt to divide a Rational by zero"),
+            Err(_) => panic!("Rational division overflowed"),
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        self.checked_neg().expect("Rational negation overflowed")
+    }
+}
+
+impl FromStr for Rational {
+    type Err = RationalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RationalError::Parse(s.to_string());
+
+        match s.split_once('/') {
+            Some((numerator, denominator)) => {
+                let numerator = numerator.trim().parse().map_err(|_| invalid())?;
+                let denominator = denominator.trim().parse().map_err(|_| invalid())?;
+                Rational::new(numerator, denominator)
+            },
+            None => s.trim().parse().map(Rational::from_integer).map_err(|_| invalid()),
+        }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// Runs the same operation with `f64` and with `Rational`.
+pub fn compare_with_f64(a: &str, operator: Operator, b: &str) -> Result<(f64, Rational), RationalError> {
+    let (x, y): (Rational, Rational) = (a.parse()?, b.parse()?);
+    let (float_x, float_y) = (x.to_f64(), y.to_f64());
+
+    let result = match operator {
+        Operator::Add => (float_x + float_y, x.checked_add(&y)?),
+        Operator::Sub => (float_x - float_y, x.checked_sub(&y)?),
+        Operator::Mul => (float_x * float_y, x.checked_mul(&y)?),
+        Operator::Div => (float_x / float_y, x.checked_div(&y)?),
+    };
+
+    Ok(result)
+}
+
+pub fn print_comparison(a: &str, operator: Operator, b: &str) -> Result<(), RationalError> {
+    let (float, rational) = compare_with_f64(a, operator, b)?;
+
+    println!("f64      : {}", float);
+    println!("Rational : {}", rational);
+    println!("as f64   : {}", rational.to_f64());
+
+    Ok(())
+}
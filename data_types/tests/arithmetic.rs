@@ -0,0 +1,48 @@
+use data_types::arithmetic::{operation_table, ArithmeticError, OperationRow};
+
+fn row(type_name: &str, a: &str, b: &str, operation: &str) -> OperationRow {
+    operation_table(type_name, a, b)
+        .unwrap()
+        .into_iter()
+        .find(|row| row.operation == operation)
+        .unwrap()
+}
+
+fn modes(row: &OperationRow) -> [&str; 4] {
+    [&row.checked, &row.wrapping, &row.saturating, &row.overflowing]
+}
+
+#[test]
+fn min_divided_by_minus_one_overflows() {
+    assert_eq!(modes(&row("i8", "-128", "-1", "div")), ["None", "-128", "127", "(-128, true)"]);
+    assert_eq!(modes(&row("i8", "-128", "-1", "rem")), ["None", "0", "n/a", "(0, true)"]);
+    assert_eq!(modes(&row("i64", &i64::MIN.to_string(), "-1", "div"))[2], i64::MAX.to_string());
+}
+
+#[test]
+fn dividing_by_zero_panics_in_every_mode_but_checked() {
+    for operation in ["div", "rem"] {
+        let row = row("u32", "7", "0", operation);
+        assert_eq!(row.checked, "None");
+        assert_eq!(row.wrapping, "panics");
+        assert_eq!(row.overflowing, "panics");
+    }
+    assert_eq!(row("u32", "7", "0", "div").saturating, "panics");
+}
+
+#[test]
+fn overflowing_addition_and_subtraction() {
+    assert_eq!(modes(&row("u8", "250", "10", "add")), ["None", "4", "255", "(4, true)"]);
+    assert_eq!(modes(&row("u8", "5", "10", "sub")), ["None", "251", "0", "(251, true)"]);
+    assert_eq!(modes(&row("i16", "200", "200", "mul")), ["None", "-25536", "32767", "(-25536, true)"]);
+    assert_eq!(modes(&row("i32", "1_000", "7", "add")), ["Some(1007)", "1007", "1007", "(1007, false)"]);
+}
+
+#[test]
+fn unknown_types_and_invalid_operands_are_errors() {
+    assert_eq!(operation_table("f64", "1", "2"), Err(ArithmeticError::UnknownType(String::from("f64"))));
+    assert_eq!(
+        operation_table("u8", "256", "1"),
+        Err(ArithmeticError::InvalidOperand { value: String::from("256"), type_name: String::from("u8") })
+    );
+}
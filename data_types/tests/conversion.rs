@@ -0,0 +1,84 @@
+use data_types::conversion::{conversion_table, convert, ConversionError, SafeFrom};
+
+fn out_of_range(value: &str, to: &'static str) -> ConversionError {
+    ConversionError::OutOfRange { value: value.to_string(), to }
+}
+
+fn precision_loss(value: &str, to: &'static str) -> ConversionError {
+    ConversionError::PrecisionLoss { value: value.to_string(), to }
+}
+
+#[test]
+fn integer_max_as_f64_rounds_up_out_of_range() {
+    // i64::MAX as f64 is 2^63, one more than i64::MAX.
+    let rounded_max = i64::MAX as f64;
+    assert_eq!(i64::safe_from(rounded_max), Err(out_of_range("9.223372036854776e18", "i64")));
+    // The largest f64 below 2^63 is 1024 less.
+    assert_eq!(i64::safe_from(rounded_max - 1024.0), Ok(i64::MAX - 1023));
+    assert_eq!(i64::safe_from(i64::MIN as f64), Ok(i64::MIN));
+
+    let u128_max = u128::MAX as f64;
+    assert_eq!(u128::safe_from(u128_max), Err(out_of_range("3.402823669209385e38", "u128")));
+    assert_eq!(u128::safe_from(u128_max / 2.0), Ok(1 << 127));
+    assert_eq!(f64::safe_from(u128::MAX), Err(precision_loss(&u128::MAX.to_string(), "f64")));
+    assert_eq!(f32::safe_from(u128::MAX), Err(precision_loss(&u128::MAX.to_string(), "f32")));
+}
+
+#[test]
+fn small_integer_boundaries() {
+    assert_eq!(i8::safe_from(-128.0), Ok(-128));
+    assert_eq!(i8::safe_from(-129.0), Err(out_of_range("-129.0", "i8")));
+    assert_eq!(u8::safe_from(255.0_f32), Ok(255));
+    assert_eq!(u8::safe_from(256.0_f32), Err(out_of_range("256.0", "u8")));
+    assert_eq!(u8::safe_from(300_u16), Err(out_of_range("300", "u8")));
+    assert_eq!(u16::safe_from(-1_i32), Err(out_of_range("-1", "u16")));
+}
+
+#[test]
+fn negative_zero_is_zero() {
+    assert_eq!(u8::safe_from(-0.0), Ok(0));
+    assert_eq!(i32::safe_from(-0.0_f32), Ok(0));
+}
+
+#[test]
+fn nan_infinity_and_fractions_have_no_integer() {
+    assert_eq!(i32::safe_from(f64::NAN), Err(ConversionError::NotFinite { value: String::from("NaN"), to: "i32" }));
+    assert_eq!(u64::safe_from(f32::INFINITY), Err(ConversionError::NotFinite { value: String::from("inf"), to: "u64" }));
+    assert_eq!(i8::safe_from(1.5), Err(ConversionError::Fractional { value: String::from("1.5"), to: "i8" }));
+}
+
+#[test]
+fn integers_too_wide_for_the_mantissa_lose_precision() {
+    assert_eq!(f32::safe_from(16_777_216_u32), Ok(16_777_216.0));
+    assert_eq!(f32::safe_from(16_777_217_u32), Err(precision_loss("16777217", "f32")));
+    assert_eq!(f32::safe_from(u32::MAX), Err(precision_loss("4294967295", "f32")));
+    assert_eq!(f64::safe_from(u32::MAX), Ok(4_294_967_295.0));
+    assert_eq!(f64::safe_from((1_u64 << 53) + 1), Err(precision_loss("9007199254740993", "f64")));
+}
+
+#[test]
+fn f64_to_f32_overflow_and_rounding() {
+    assert_eq!(f32::safe_from(1e39), Err(out_of_range("1e39", "f32")));
+    assert_eq!(f32::safe_from(f64::MAX), Err(out_of_range("1.7976931348623157e308", "f32")));
+    assert_eq!(f32::safe_from(0.1), Err(precision_loss("0.1", "f32")));
+    assert_eq!(f32::safe_from(0.5), Ok(0.5));
+    assert_eq!(f32::safe_from(f64::INFINITY), Ok(f32::INFINITY));
+    assert!(f32::safe_from(f64::NAN).unwrap().is_nan());
+}
+
+#[test]
+fn large_floats_are_written_with_an_exponent() {
+    let rows = conversion_table("f64", "1e300").unwrap();
+    let i8_row = rows.iter().find(|row| row.to == "i8").unwrap();
+    let f64_row = rows.iter().find(|row| row.to == "f64").unwrap();
+
+    assert_eq!(i8_row.checked, Err(out_of_range("1e300", "i8")));
+    assert_eq!((f64_row.as_cast.as_str(), f64_row.checked.as_deref()), ("1e300", Ok("1e300")));
+    assert_eq!(rows.iter().find(|row| row.to == "f32").unwrap().as_cast, "inf");
+}
+
+#[test]
+fn convert_picks_the_target_by_inference() {
+    let value: Result<u8, _> = convert(200_i32);
+    assert_eq!(value, Ok(200));
+}
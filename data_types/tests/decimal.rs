@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+
+use data_types::decimal::{compare_with_f64, Decimal, DecimalError, RoundingMode, MAX_SCALE};
+use data_types::operator::Operator;
+
+fn decimal(text: &str) -> Decimal {
+    text.parse().unwrap()
+}
+
+#[test]
+fn money_subtraction_is_exact() {
+    let (float, exact) = compare_with_f64("5.5", Operator::Sub, "6.23", 10, RoundingMode::HalfEven).unwrap();
+
+    assert_eq!(float, -0.7300000000000004);
+    assert_eq!(exact.to_string(), "-0.73");
+    assert_eq!(decimal("0.1") + decimal("0.2"), decimal("0.3"));
+}
+
+#[test]
+fn parsing_and_display_keep_the_scale() {
+    assert_eq!(decimal("5.50").to_string(), "5.50");
+    assert_eq!(decimal("-0.05").to_string(), "-0.05");
+    assert_eq!(decimal("+.5").to_string(), "0.5");
+    assert_eq!(decimal("7.").to_string(), "7");
+    assert_eq!((decimal("5.50").mantissa(), decimal("5.50").scale()), (550, 2));
+
+    assert_eq!("".parse::<Decimal>(), Err(DecimalError::Parse(String::new())));
+    assert_eq!("1.2.3".parse::<Decimal>(), Err(DecimalError::Parse(String::from("1.2.3"))));
+    assert_eq!("1e3".parse::<Decimal>(), Err(DecimalError::Parse(String::from("1e3"))));
+    assert_eq!(format!("0.{}1", "0".repeat(28)).parse::<Decimal>(), Err(DecimalError::ScaleTooLarge(29)));
+}
+
+#[test]
+fn values_compare_across_scales() {
+    assert_eq!(decimal("1.5"), decimal("1.50"));
+    assert_eq!(decimal("-1.5").cmp(&decimal("-1.2")), Ordering::Less);
+    assert_eq!(decimal("0.5").cmp(&decimal("-0.5")), Ordering::Greater);
+    assert_eq!(decimal("2").cmp(&decimal("1.9999999999999999999999999999")), Ordering::Greater);
+    assert_eq!(decimal("-0.0"), Decimal::ZERO);
+}
+
+#[test]
+fn every_rounding_mode_on_positive_and_negative_ties() {
+    // (mode, 2.5, -2.5, 2.4, -2.6)
+    let cases = [
+        (RoundingMode::HalfEven, "2", "-2", "2", "-3"),
+        (RoundingMode::HalfUp, "3", "-3", "2", "-3"),
+        (RoundingMode::Down, "2", "-2", "2", "-2"),
+        (RoundingMode::Up, "3", "-3", "3", "-3"),
+        (RoundingMode::Floor, "2", "-3", "2", "-3"),
+        (RoundingMode::Ceiling, "3", "-2", "3", "-2"),
+    ];
+
+    for (mode, tie, negative_tie, below, negative_above) in cases {
+        let round = |text: &str| decimal(text).round(0, mode).unwrap().to_string();
+        assert_eq!(round("2.5"), tie, "{:?}", mode);
+        assert_eq!(round("-2.5"), negative_tie, "{:?}", mode);
+        assert_eq!(round("2.4"), below, "{:?}", mode);
+        assert_eq!(round("-2.6"), negative_above, "{:?}", mode);
+    }
+    assert_eq!(decimal("3.5").round(0, RoundingMode::HalfEven).unwrap().to_string(), "4");
+    assert_eq!(decimal("1.5").round(3, RoundingMode::Down).unwrap().to_string(), "1.500");
+}
+
+#[test]
+fn division_rounds_with_negative_operands() {
+    let divide = |a: &str, b: &str, scale, mode| decimal(a).checked_div(&decimal(b), scale, mode).unwrap().to_string();
+
+    assert_eq!(divide("1", "3", 4, RoundingMode::HalfEven), "0.3333");
+    assert_eq!(divide("2", "3", 4, RoundingMode::HalfEven), "0.6667");
+    assert_eq!(divide("-2", "3", 4, RoundingMode::HalfEven), "-0.6667");
+    assert_eq!(divide("2", "-3", 4, RoundingMode::Floor), "-0.6667");
+    assert_eq!(divide("-2", "-3", 4, RoundingMode::Down), "0.6666");
+    // 0.125 is a tie at two digits.
+    assert_eq!(divide("1", "8", 2, RoundingMode::HalfEven), "0.12");
+    assert_eq!(divide("-1", "8", 2, RoundingMode::HalfEven), "-0.12");
+    assert_eq!(divide("-1", "8", 2, RoundingMode::HalfUp), "-0.13");
+    assert_eq!(divide("5", "5.5", 10, RoundingMode::HalfEven), "0.9090909091");
+
+    assert_eq!(decimal("1").checked_div(&decimal("0.00"), 2, RoundingMode::HalfEven), Err(DecimalError::DivisionByZero));
+}
+
+#[test]
+fn multiplication_adds_scales_and_rounds_past_the_maximum() {
+    assert_eq!((decimal("5.5") * decimal("0.20")).to_string(), "1.100");
+
+    let tiny = decimal("0.00000000000001").checked_mul(&decimal("0.000000000000015")).unwrap();
+    assert_eq!(tiny.scale(), MAX_SCALE);
+    assert_eq!(tiny.to_string(), format!("0.{}2", "0".repeat(27)));
+}
+
+#[test]
+fn overflow_is_an_error_not_a_panic() {
+    let min = Decimal::new(i128::MIN, 0).unwrap();
+    let max = Decimal::new(i128::MAX, 0).unwrap();
+
+    assert_eq!(min.checked_neg(), Err(DecimalError::Overflow));
+    assert_eq!(Decimal::ZERO.checked_sub(&min), Err(DecimalError::Overflow));
+    assert_eq!(max.checked_add(&decimal("1")), Err(DecimalError::Overflow));
+    assert_eq!(max.checked_mul(&decimal("2")), Err(DecimalError::Overflow));
+    assert_eq!(min.checked_sub(&decimal("-1")).unwrap(), Decimal::new(i128::MIN + 1, 0).unwrap());
+}
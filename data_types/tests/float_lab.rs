@@ -0,0 +1,81 @@
+use data_types::float_lab::{approx_eq, sorted_with_total_cmp, special_values, ulps_between, Tolerance};
+
+fn value_of(expression: &str) -> f64 {
+    special_values()
+        .into_iter()
+        .find(|example| example.expression == expression)
+        .map(|example| example.value)
+        .unwrap()
+}
+
+#[test]
+fn arithmetic_produces_special_values() {
+    assert!(value_of("0.0 / 0.0").is_nan());
+    assert!(value_of("f64::INFINITY - f64::INFINITY").is_nan());
+    assert!(value_of("(-1.0_f64).sqrt()").is_nan());
+    assert_eq!(value_of("1.0 / 0.0"), f64::INFINITY);
+    assert_eq!(value_of("-1.0 / 0.0"), f64::NEG_INFINITY);
+    assert_eq!(value_of("f64::MAX * 2.0"), f64::INFINITY);
+
+    let negative_zero = value_of("1.0 / f64::NEG_INFINITY");
+    assert!(negative_zero == 0.0 && negative_zero.is_sign_negative());
+
+    assert!(value_of("f64::MIN_POSITIVE").is_normal());
+    assert!(value_of("f64::MIN_POSITIVE / 2.0").is_subnormal());
+    assert!(value_of("f64::from_bits(1)").is_subnormal());
+    assert_ne!(value_of("1.0 + f64::EPSILON"), 1.0);
+    assert_eq!(value_of("1.0 + f64::EPSILON / 2.0"), 1.0);
+}
+
+#[test]
+#[allow(clippy::eq_op)]
+fn nan_is_not_equal_to_itself() {
+    let nan = f64::NAN;
+
+    assert!(nan != nan);
+    assert_eq!(nan.partial_cmp(&nan), None);
+    assert_eq!(nan.total_cmp(&nan), std::cmp::Ordering::Equal);
+    assert_eq!((-0.0_f64).total_cmp(&0.0), std::cmp::Ordering::Less);
+}
+
+#[test]
+fn total_cmp_sorts_every_value() {
+    let sorted = sorted_with_total_cmp(&[3.0, f64::NAN, 0.0, f64::NEG_INFINITY, -0.0, -f64::NAN, -1.5]);
+
+    assert!(sorted[0].is_nan() && sorted[0].is_sign_negative());
+    assert_eq!(sorted[1], f64::NEG_INFINITY);
+    assert_eq!(sorted[2], -1.5);
+    assert!(sorted[3] == 0.0 && sorted[3].is_sign_negative());
+    assert!(sorted[4] == 0.0 && sorted[4].is_sign_positive());
+    assert_eq!(sorted[5], 3.0);
+    assert!(sorted[6].is_nan() && sorted[6].is_sign_positive());
+}
+
+#[test]
+fn ulps_count_representable_steps() {
+    assert_eq!(ulps_between(1.0, 1.0), Some(0));
+    assert_eq!(ulps_between(1.0, 1.0 + f64::EPSILON), Some(1));
+    assert_eq!(ulps_between(-0.0, 0.0), Some(0));
+    assert_eq!(ulps_between(-f64::from_bits(1), f64::from_bits(1)), Some(2));
+    assert_eq!(ulps_between(0.1 + 0.2, 0.3), Some(1));
+    assert_eq!(ulps_between(f64::NAN, 1.0), None);
+}
+
+#[test]
+fn approx_eq_tolerance_modes() {
+    let (a, b) = (0.1 + 0.2, 0.3);
+    assert!(approx_eq(a, b, Tolerance::Absolute(1e-12)));
+    assert!(approx_eq(a, b, Tolerance::Relative(f64::EPSILON)));
+    assert!(approx_eq(a, b, Tolerance::Ulps(1)));
+    assert!(!approx_eq(a, b, Tolerance::Ulps(0)));
+
+    // An absolute tolerance is too strict for big numbers and too loose for tiny ones.
+    assert!(!approx_eq(1e20, 1e20 + 1e5, Tolerance::Absolute(1e-12)));
+    assert!(approx_eq(1e20, 1e20 + 1e5, Tolerance::Relative(1e-12)));
+    assert!(approx_eq(1e-20, 2e-20, Tolerance::Absolute(1e-12)));
+    assert!(!approx_eq(1e-20, 2e-20, Tolerance::Relative(1e-12)));
+
+    assert!(!approx_eq(f64::NAN, f64::NAN, Tolerance::Absolute(f64::INFINITY)));
+    assert!(approx_eq(f64::INFINITY, f64::INFINITY, Tolerance::Ulps(0)));
+    assert!(!approx_eq(f64::MAX, f64::INFINITY, Tolerance::Ulps(1)));
+}
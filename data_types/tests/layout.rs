@@ -0,0 +1,46 @@
+use data_types::layout::{layouts, Layout};
+
+fn layout_of(type_name: &str) -> Layout {
+    layouts().into_iter().find(|layout| layout.type_name == type_name).unwrap()
+}
+
+#[test]
+fn bool_is_one_byte() {
+    let layout = layout_of("bool");
+    assert_eq!(layout.size, 1);
+    assert_eq!(layout.range, Some((String::from("false"), String::from("true"))));
+}
+
+#[test]
+fn char_is_four_bytes_of_unicode_scalar_value() {
+    let layout = layout_of("char");
+    assert_eq!(layout.size, 4);
+    assert_eq!(layout.range, Some((String::from("U+0000"), String::from("U+10FFFF"))));
+}
+
+#[test]
+fn isize_and_usize_match_the_pointer_width() {
+    let pointer_bits = (std::mem::size_of::<*const u8>() * 8) as u32;
+    assert_eq!(layout_of("isize").bits, pointer_bits);
+    assert_eq!(layout_of("usize").bits, pointer_bits);
+}
+
+#[test]
+fn integer_bits_and_ranges_match_their_names() {
+    for (type_name, bits) in [("i8", 8), ("u16", 16), ("i32", 32), ("u64", 64), ("i128", 128)] {
+        let layout = layout_of(type_name);
+        assert_eq!(layout.bits, bits);
+        assert_eq!(layout.size * 8, bits as usize);
+    }
+    assert_eq!(layout_of("i8").range, Some((String::from("-128"), String::from("127"))));
+    assert_eq!(layout_of("u8").range, Some((String::from("0"), String::from("255"))));
+}
+
+#[test]
+fn compound_types_hold_their_elements() {
+    assert_eq!(layout_of("()").size, 0);
+    assert_eq!(layout_of("[i32; 5]").size, 5 * 4);
+    assert!(layout_of("(i32, f64, i32)").size >= 4 + 8 + 4);
+    assert_eq!(layout_of("(i32, f64, i32)").align, std::mem::align_of::<f64>());
+    assert_eq!(layout_of("(i32, f64, i32)").range, None);
+}
@@ -0,0 +1,67 @@
+use data_types::literal::{parse_literal, IntValue, LiteralError, Radix};
+
+#[test]
+fn prefixes_and_separators() {
+    assert_eq!(parse_literal("0xffu8"), Ok(IntValue::U8(255)));
+    assert_eq!(parse_literal("0b1111_0000"), Ok(IntValue::I32(240)));
+    assert_eq!(parse_literal("0o77"), Ok(IntValue::I32(63)));
+    assert_eq!(parse_literal("98_222"), Ok(IntValue::I32(98_222)));
+}
+
+#[test]
+fn byte_literals_are_u8() {
+    assert_eq!(parse_literal("b'A'"), Ok(IntValue::U8(65)));
+    assert_eq!(parse_literal("b'\\x7f'"), Ok(IntValue::U8(127)));
+    assert_eq!(parse_literal("b'\\n'"), Ok(IntValue::U8(10)));
+    assert_eq!(parse_literal("b'ß'"), Err(LiteralError::InvalidByte(String::from("b'ß'"))));
+}
+
+#[test]
+fn suffixes_pick_the_type() {
+    assert_eq!(parse_literal("58u8"), Ok(IntValue::U8(58)));
+    assert_eq!(parse_literal("58_i64"), Ok(IntValue::I64(58)));
+    assert_eq!(parse_literal("-0x80i8"), Ok(IntValue::I8(-128)));
+    assert_eq!(parse_literal("1e3"), Err(LiteralError::UnknownSuffix(String::from("e3"))));
+}
+
+#[test]
+fn values_must_fit_the_type() {
+    assert_eq!(parse_literal("-1u8"), Err(LiteralError::NegativeUnsigned(String::from("-1u8"))));
+    assert_eq!(
+        parse_literal("256u8"),
+        Err(LiteralError::OutOfRange { literal: String::from("256u8"), type_name: "u8" })
+    );
+    assert_eq!(
+        parse_literal("0x81i8"),
+        Err(LiteralError::OutOfRange { literal: String::from("0x81i8"), type_name: "i8" })
+    );
+}
+
+#[test]
+fn i128_min_has_no_positive_counterpart() {
+    let min = format!("{}i128", i128::MIN);
+    assert_eq!(parse_literal(&min), Ok(IntValue::I128(i128::MIN)));
+    assert_eq!(IntValue::I128(i128::MIN).sign_and_magnitude(), (true, 1 << 127));
+
+    let max_plus_one = format!("{}i128", i128::MIN.unsigned_abs());
+    assert_eq!(
+        parse_literal(&max_plus_one),
+        Err(LiteralError::OutOfRange { literal: max_plus_one.clone(), type_name: "i128" })
+    );
+}
+
+#[test]
+fn missing_or_invalid_digits() {
+    assert_eq!(parse_literal(""), Err(LiteralError::Empty));
+    assert_eq!(parse_literal("0x"), Err(LiteralError::NoDigits(String::from("0x"))));
+    assert_eq!(parse_literal("0b102"), Err(LiteralError::InvalidDigit { digit: '2', radix: 2 }));
+}
+
+#[test]
+fn formats_in_every_radix() {
+    let value = IntValue::I32(-240);
+    assert_eq!(value.format(Radix::Hex, false), "-0xf0");
+    assert_eq!(value.format(Radix::Binary, true), "-0b1111_0000");
+    assert_eq!(IntValue::U32(1_000_000).format(Radix::Decimal, true), "1_000_000");
+    assert_eq!(IntValue::U8(58).to_string(), "58u8");
+}
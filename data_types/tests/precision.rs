@@ -0,0 +1,77 @@
+use data_types::precision::{precision_report, FloatReport};
+
+fn reports(literal: &str) -> (FloatReport, FloatReport) {
+    let [f32_report, f64_report] = precision_report(literal).unwrap();
+    (f32_report, f64_report)
+}
+
+#[test]
+fn one_tenth_is_correct_to_sixteen_digits_in_f64() {
+    let (f32_report, f64_report) = reports("0.1");
+
+    assert_eq!(f64_report.stored, "0.1000000000000000055511151231257827021181583404541015625");
+    assert_eq!(f64_report.error, 5.551115123125783e-18);
+    assert_eq!(f64_report.significant_digits, Some(16));
+    assert_eq!(f32_report.significant_digits, Some(8));
+}
+
+#[test]
+fn stored_value_below_the_literal_still_counts_matching_digits() {
+    // 0.29999999999999998889... shares no leading digit with 0.3 but is just as accurate as 0.1.
+    let (f32_report, f64_report) = reports("0.3");
+
+    assert_eq!(f64_report.stored, "0.299999999999999988897769753748434595763683319091796875");
+    assert!(f64_report.error < 0.0);
+    assert_eq!(f64_report.significant_digits, Some(16));
+    assert_eq!(f32_report.significant_digits, Some(7));
+}
+
+#[test]
+fn readme_literal_loses_digits_in_both_types() {
+    let (f32_report, f64_report) = reports("21.321654651651651");
+
+    assert_eq!(f32_report.stored, "21.3216552734375");
+    assert_eq!(f32_report.significant_digits, Some(7));
+    assert_eq!(f64_report.stored, "21.321654651651652301325157168321311473846435546875");
+    assert_eq!(f64_report.significant_digits, Some(16));
+}
+
+#[test]
+fn exactly_representable_literals_have_no_error() {
+    let (f32_report, f64_report) = reports("0.5");
+
+    assert_eq!((f32_report.error, f32_report.significant_digits), (0.0, None));
+    assert_eq!((f64_report.error, f64_report.significant_digits), (0.0, None));
+    assert_eq!(f64_report.exponent, -1);
+}
+
+#[test]
+fn subnormal_f32_has_a_zero_exponent_field_and_fewer_digits() {
+    // 1e-41 is below the smallest normal f32, about 1.18e-38.
+    let literal = format!("0.{}1", "0".repeat(40));
+    let (f32_report, f64_report) = reports(&literal);
+
+    assert_eq!(f32_report.exponent_bits, "00000000");
+    assert_eq!(f32_report.exponent, -126);
+    assert_eq!(f32_report.significant_digits, Some(5));
+    assert_eq!(f64_report.significant_digits, Some(17));
+}
+
+#[test]
+fn f32_overflows_to_infinity() {
+    let literal = format!("1{}", "0".repeat(40));
+    let (f32_report, f64_report) = reports(&literal);
+
+    assert_eq!(f32_report.stored, "inf");
+    assert_eq!(f32_report.exponent_bits, "11111111");
+    assert_eq!(f32_report.mantissa_bits, "0".repeat(23));
+    assert_eq!(f32_report.significant_digits, Some(0));
+    assert!(f64_report.error.is_finite());
+}
+
+#[test]
+fn invalid_literals_are_rejected() {
+    assert!(precision_report("abc").is_err());
+    assert!(precision_report("1.2.3").is_err());
+    assert!(precision_report(".").is_err());
+}
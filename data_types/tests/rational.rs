@@ -0,0 +1,75 @@
+use std::cmp::Ordering;
+
+use data_types::operator::Operator;
+use data_types::rational::{compare_with_f64, Rational, RationalError};
+
+fn rational(text: &str) -> Rational {
+    text.parse().unwrap()
+}
+
+#[test]
+fn fractions_are_stored_in_lowest_terms() {
+    let half = Rational::new(2, -4).unwrap();
+    assert_eq!((half.numerator(), half.denominator()), (-1, 2));
+    assert_eq!(half.to_string(), "-1/2");
+    assert_eq!(Rational::new(6, 3).unwrap().to_string(), "2");
+    assert_eq!(Rational::new(0, -5).unwrap(), Rational::ZERO);
+    assert_eq!(Rational::new(1, 0), Err(RationalError::ZeroDenominator));
+}
+
+#[test]
+fn results_that_do_not_fit_in_i64_overflow() {
+    assert_eq!(Rational::new(i64::MIN, -1), Err(RationalError::Overflow));
+    assert_eq!(Rational::from(i64::MIN).checked_neg(), Err(RationalError::Overflow));
+    assert_eq!(Rational::from(i64::MAX).checked_add(&Rational::ONE), Err(RationalError::Overflow));
+    // The intermediate product overflows i64, but the reduced result fits.
+    let big = Rational::new(i64::MAX, 2).unwrap();
+    assert_eq!(big.checked_mul(&Rational::from(2)), Ok(Rational::from(i64::MAX)));
+}
+
+#[test]
+fn ordering_compares_values() {
+    assert_eq!(rational("1/3").cmp(&rational("1/2")), Ordering::Less);
+    assert_eq!(rational("-1/2").cmp(&rational("-1/3")), Ordering::Less);
+    assert_eq!(rational("2/4"), rational("1/2"));
+    assert_eq!(rational(&format!("{}/1", i64::MAX)).cmp(&rational(&format!("{}/2", i64::MAX))), Ordering::Greater);
+
+    let mut values = vec![rational("3/4"), rational("-2"), rational("1/8"), rational("0")];
+    values.sort();
+    assert_eq!(values, [rational("-2"), rational("0"), rational("1/8"), rational("3/4")]);
+}
+
+#[test]
+fn parsing_errors() {
+    assert_eq!(rational(" 3 / 6 "), rational("1/2"));
+    assert_eq!(rational("-7"), Rational::from(-7));
+    assert_eq!("0.5".parse::<Rational>(), Err(RationalError::Parse(String::from("0.5"))));
+    assert_eq!("1/".parse::<Rational>(), Err(RationalError::Parse(String::from("1/"))));
+    assert_eq!("1/2/3".parse::<Rational>(), Err(RationalError::Parse(String::from("1/2/3"))));
+    assert_eq!("1/0".parse::<Rational>(), Err(RationalError::ZeroDenominator));
+}
+
+#[test]
+fn operators_are_exact() {
+    assert_eq!(rational("1/3") + rational("1/6"), rational("1/2"));
+    assert_eq!(rational("1/3") - rational("1/2"), rational("-1/6"));
+    assert_eq!(rational("2/3") * rational("3/4"), rational("1/2"));
+    assert_eq!(rational("5") / rational("11/2"), rational("10/11"));
+    assert_eq!(-rational("1/2"), rational("-1/2"));
+    assert_eq!(rational("1/3").checked_div(&Rational::ZERO), Err(RationalError::DivisionByZero));
+    assert_eq!(rational("-2/3").recip(), Ok(rational("-3/2")));
+}
+
+#[test]
+#[should_panic(expected = "divide a Rational by zero")]
+fn dividing_by_zero_panics() {
+    let _ = Rational::ONE / Rational::ZERO;
+}
+
+#[test]
+fn floats_only_on_request() {
+    let (float, exact) = compare_with_f64("5", Operator::Div, "11/2").unwrap();
+    assert_eq!(exact, rational("10/11"));
+    assert_eq!(exact.to_f64(), float);
+    assert_eq!(rational("1/3").to_f32(), 1.0 / 3.0);
+}
@@ -0,0 +1,129 @@
+use std::path::PathBuf;
+
+use crate::Difficulty;
+
+pub const USAGE: &str = "Usage: guessing_game [scores|reverse|serve] [options]
+
+Commands:
+    scores                     Print the best results for each difficulty
+    reverse                    You pick the number and the computer guesses it
+    serve                      Run a game per TCP connection, see `guessing_game::server`
+
+Options:
+    --seed <number>            Use a fixed seed so the same secret number is picked every time,
+                               wins with a seed are not saved as high scores
+    --difficulty <level>       easy (1-10), normal (1-100) or hard (1-1000), defaults to normal
+    --max-attempts <number>    End the game with a loss after this many guesses (shared by all players)
+    --players <names>          Comma separated names, two or more players take turns
+    --hints                    Also say how close each guess was (burning, warm or cold)
+    --scores-file <path>       Read and write high scores here instead of the user data directory
+    --port <number>            Port for `serve` to listen on, defaults to 7878
+    -h, --help                 Print this help";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    #[default]
+    Play,
+    Scores,
+    Reverse,
+    Serve,
+}
+
+pub const DEFAULT_PORT: u16 = 7878;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Options {
+    pub command: Command,
+    pub seed: Option<u64>,
+    pub difficulty: Difficulty,
+    pub max_attempts: Option<u32>,
+    pub hints: bool,
+    pub players: Vec<String>,
+    pub scores_file: Option<PathBuf>,
+    pub port: u16,
+    pub help: bool,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            command: Command::default(),
+            seed: None,
+            difficulty: Difficulty::default(),
+            max_attempts: None,
+            hints: false,
+            players: Vec::new(),
+            scores_file: None,
+            port: DEFAULT_PORT,
+            help: false,
+        }
+    }
+}
+
+pub fn parse_args<I>(args: I) -> Result<Options, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut options = Options::default();
+    let mut args = args.into_iter().peekable();
+
+    match args.peek().map(String::as_str) {
+        Some("scores") => options.command = Command::Scores,
+        Some("reverse") => options.command = Command::Reverse,
+        Some("serve") => options.command = Command::Serve,
+        _ => {},
+    }
+    if options.command != Command::Play {
+        args.next();
+    }
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = args.next().ok_or("--seed needs a value")?;
+                let seed = value
+                    .parse()
+                    .map_err(|_| format!("'{}' is not a valid seed", value))?;
+                options.seed = Some(seed);
+            },
+            "--difficulty" => {
+                let value = args.next().ok_or("--difficulty needs a value")?;
+                options.difficulty = value.parse()?;
+            },
+            "--max-attempts" => {
+                let value = args.next().ok_or("--max-attempts needs a value")?;
+                let max_attempts = match value.parse() {
+                    Ok(0) | Err(_) => return Err(format!("'{}' is not a valid number of attempts", value)),
+                    Ok(max_attempts) => max_attempts,
+                };
+                options.max_attempts = Some(max_attempts);
+            },
+            "--hints" => options.hints = true,
+            "--players" => {
+                let value = args.next().ok_or("--players needs a value")?;
+                let players: Vec<String> = value
+                    .split(',')
+                    .map(|name| name.trim().to_string())
+                    .collect();
+                if players.len() < 2 || players.iter().any(String::is_empty) {
+                    return Err(format!("'{}' should be two or more comma separated names", value));
+                }
+                options.players = players;
+            },
+            "--scores-file" => {
+                let value = args.next().ok_or("--scores-file needs a value")?;
+                options.scores_file = Some(PathBuf::from(value));
+            },
+            "--port" => {
+                let value = args.next().ok_or("--port needs a value")?;
+                options.port = value
+                    .parse()
+                    .map_err(|_| format!("'{}' is not a valid port", value))?;
+            },
+            "-h" | "--help" => options.help = true,
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    Ok(options)
+}
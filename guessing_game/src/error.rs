@@ -0,0 +1,25 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuessError {
+    Empty,
+    NotANumber(String),
+    OutOfRange { guess: i32, min: i32, max: i32 },
+    GameOver,
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GuessError::Empty => write!(f, "You didn't type anything"),
+            GuessError::NotANumber(input) => write!(f, "'{}' is not a number", input),
+            GuessError::OutOfRange { guess, min, max } => {
+                write!(f, "{} is out of range, the secret number is between {} and {}", guess, min, max)
+            },
+            GuessError::GameOver => write!(f, "The game is already over"),
+        }
+    }
+}
+
+impl Error for GuessError {}
@@ -0,0 +1,120 @@
+use std::cmp::Ordering;
+
+use rand::Rng;
+
+use crate::hint::Hint;
+use crate::{check_range, generate_random_number, parse_guess, Difficulty, GuessError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The guess was wrong, the `Ordering` is the guess compared to the secret number.
+    Wrong(Ordering),
+    Won { attempts: u32 },
+    Lost { secret_number: i32 },
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    secret_number: i32,
+    difficulty: Difficulty,
+    max_attempts: Option<u32>,
+    attempts: u32,
+    history: Vec<i32>,
+    finished: bool,
+}
+
+impl Game {
+    pub fn new(secret_number: i32, difficulty: Difficulty, max_attempts: Option<u32>) -> Game {
+        Game {
+            secret_number,
+            difficulty,
+            max_attempts,
+            attempts: 0,
+            history: Vec::new(),
+            finished: false,
+        }
+    }
+
+    pub fn with_rng<R: Rng + ?Sized>(rng: &mut R, difficulty: Difficulty, max_attempts: Option<u32>) -> Game {
+        Game::new(generate_random_number(rng, difficulty), difficulty, max_attempts)
+    }
+
+    pub fn submit_guess(&mut self, guess: i32) -> Result<Outcome, GuessError> {
+        if self.finished {
+            return Err(GuessError::GameOver);
+        }
+
+        let guess = check_range(guess, self.difficulty)?;
+        self.attempts += 1;
+        self.history.push(guess);
+
+        let outcome = match guess.cmp(&self.secret_number) {
+            Ordering::Equal => Outcome::Won { attempts: self.attempts },
+            ordering => {
+                if self.attempts_remaining() == Some(0) {
+                    Outcome::Lost { secret_number: self.secret_number }
+                } else {
+                    Outcome::Wrong(ordering)
+                }
+            }
+        };
+
+        if !matches!(outcome, Outcome::Wrong(_)) {
+            self.finished = true;
+        }
+
+        Ok(outcome)
+    }
+
+    pub fn submit_input(&mut self, input: &str) -> Result<Outcome, GuessError> {
+        if self.finished {
+            return Err(GuessError::GameOver);
+        }
+
+        let guess = parse_guess(input, self.difficulty)?;
+        self.submit_guess(guess)
+    }
+
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn attempts_remaining(&self) -> Option<u32> {
+        self.max_attempts.map(|max| max.saturating_sub(self.attempts))
+    }
+
+    /// Every valid guess so far, oldest first.
+    pub fn history(&self) -> &[i32] {
+        &self.history
+    }
+
+    /// How close the latest guess was, and whether it was closer than the one before it.
+    pub fn hint(&self) -> Option<Hint> {
+        let (_, earlier) = self.history.split_last()?;
+        self.hint_since(earlier.last().copied())
+    }
+
+    /// Like `hint`, but the trend compares with `previous` instead of the guess just
+    /// before, so each player in a multiplayer game is compared with their own guesses.
+    pub fn hint_since(&self, previous: Option<i32>) -> Option<Hint> {
+        let guess = self.history.last()?;
+        Some(Hint::new(self.secret_number, *guess, previous, self.difficulty))
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Only revealed once the game is over.
+    pub fn secret_number(&self) -> Option<i32> {
+        if self.finished {
+            Some(self.secret_number)
+        } else {
+            None
+        }
+    }
+}
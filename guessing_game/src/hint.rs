@@ -0,0 +1,80 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::Difficulty;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Band {
+    Burning,
+    Warm,
+    Cold,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Closer,
+    Farther,
+    Same,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hint {
+    pub band: Band,
+    /// `None` for the first guess, there is nothing to compare it with.
+    pub trend: Option<Trend>,
+}
+
+impl Band {
+    /// Burning is within 5% of the range and warm within 20%, with a floor so the
+    /// small easy range still gets useful bands.
+    pub fn of(distance: u32, difficulty: Difficulty) -> Band {
+        let range = difficulty.range();
+        let span = (range.end() - range.start()) as u32;
+
+        if distance <= (span / 20).max(1) {
+            Band::Burning
+        } else if distance <= (span / 5).max(2) {
+            Band::Warm
+        } else {
+            Band::Cold
+        }
+    }
+}
+
+impl Hint {
+    pub fn new(secret_number: i32, guess: i32, previous: Option<i32>, difficulty: Difficulty) -> Hint {
+        let distance = guess.abs_diff(secret_number);
+
+        let trend = previous.map(|previous| {
+            let previous_distance = previous.abs_diff(secret_number);
+            match distance.cmp(&previous_distance) {
+                Ordering::Less => Trend::Closer,
+                Ordering::Greater => Trend::Farther,
+                Ordering::Equal => Trend::Same,
+            }
+        });
+
+        Hint {
+            band: Band::of(distance, difficulty),
+            trend,
+        }
+    }
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let band = match self.band {
+            Band::Burning => "Burning",
+            Band::Warm => "Warm",
+            Band::Cold => "Cold",
+        };
+        write!(f, "{}", band)?;
+
+        match self.trend {
+            Some(Trend::Closer) => write!(f, ", closer than your last guess"),
+            Some(Trend::Farther) => write!(f, ", farther than your last guess"),
+            Some(Trend::Same) => write!(f, ", as far as your last guess"),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+pub mod cli;
+pub mod difficulty;
+pub mod error;
+pub mod game;
+pub mod hint;
+pub mod multiplayer;
+pub mod scores;
+pub mod server;
+pub mod solver;
+pub mod terminal;
+
+use rand::Rng;
+
+pub use difficulty::Difficulty;
+pub use error::GuessError;
+pub use game::{Game, Outcome};
+
+pub fn generate_random_number<R: Rng + ?Sized>(rng: &mut R, difficulty: Difficulty) -> i32 {
+    rng.gen_range(difficulty.range())
+}
+
+pub fn check_range(number: i32, difficulty: Difficulty) -> Result<i32, GuessError> {
+    let range = difficulty.range();
+    if !range.contains(&number) {
+        return Err(GuessError::OutOfRange {
+            guess: number,
+            min: *range.start(),
+            max: *range.end(),
+        });
+    }
+
+    Ok(number)
+}
+
+pub fn parse_guess(guess: &str, difficulty: Difficulty) -> Result<i32, GuessError> {
+    let guess = guess.trim();
+
+    if guess.is_empty() {
+        return Err(GuessError::Empty);
+    }
+
+    let number: i32 = match guess.parse() {
+        Ok(num) => num,
+        Err(_) => return Err(GuessError::NotANumber(guess.to_string())),
+    };
+
+    check_range(number, difficulty)
+}
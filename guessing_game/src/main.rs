@@ -0,0 +1,118 @@
+use std::env;
+use std::net::TcpListener;
+use std::path::Path;
+use std::process;
+
+use guessing_game::cli::{self, Command};
+use guessing_game::multiplayer::Multiplayer;
+use guessing_game::scores::{self, Score, ScoreBoard};
+use guessing_game::server::{self, ServerConfig};
+use guessing_game::{terminal, Difficulty, Game, Outcome};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const SCORES_PER_DIFFICULTY: usize = 5;
+
+fn main() {
+    let options = match cli::parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("Error: {}\n\n{}", err, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    if options.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
+
+    let scores_file = options.scores_file.clone().or_else(scores::default_path);
+
+    match options.command {
+        Command::Play => {
+            let mut rng = match options.seed {
+                Some(seed) => StdRng::seed_from_u64(seed),
+                None => StdRng::from_entropy(),
+            };
+
+            let mut game = Game::with_rng(&mut rng, options.difficulty, options.max_attempts);
+            if !options.players.is_empty() {
+                let mut multiplayer = match Multiplayer::new(game, options.players) {
+                    Ok(multiplayer) => multiplayer,
+                    Err(err) => {
+                        eprintln!("Error: {}", err);
+                        process::exit(2);
+                    }
+                };
+                terminal::play_multiplayer(&mut multiplayer, options.hints);
+                return;
+            }
+
+            if let Some(Outcome::Won { attempts }) = terminal::play(&mut game, options.hints) {
+                // Anyone who knows the seed knows the answer, so the score means nothing.
+                if options.seed.is_some() {
+                    println!("Played with a fixed seed, the score is not saved.");
+                } else if let Some(path) = &scores_file {
+                    save_score(path, Score::now(attempts, game.difficulty()));
+                }
+            }
+        },
+        Command::Serve => {
+            let config = ServerConfig {
+                difficulty: options.difficulty,
+                max_attempts: options.max_attempts,
+                seed: options.seed,
+            };
+
+            let result = TcpListener::bind(("127.0.0.1", options.port)).and_then(|listener| {
+                println!("Listening on {}", listener.local_addr()?);
+                server::serve(listener, config)
+            });
+
+            if let Err(err) = result {
+                eprintln!("Error: {}", err);
+                process::exit(1);
+            }
+        },
+        Command::Reverse => terminal::play_reverse(options.difficulty),
+        Command::Scores => match &scores_file {
+            Some(path) => print_scores(path),
+            None => eprintln!("Error: could not find a data directory for the score file"),
+        },
+    }
+}
+
+fn save_score(path: &Path, score: Score) {
+    let result = ScoreBoard::load(path).and_then(|mut board| {
+        board.record(score);
+        board.save(path)
+    });
+
+    if let Err(err) = result {
+        eprintln!("Error: could not save your score to {}: {}", path.display(), err);
+    }
+}
+
+fn print_scores(path: &Path) {
+    let board = match ScoreBoard::load(path) {
+        Ok(board) => board,
+        Err(err) => {
+            eprintln!("Error: could not read {}: {}", path.display(), err);
+            process::exit(1);
+        }
+    };
+
+    for difficulty in Difficulty::ALL {
+        println!("{}:", difficulty);
+
+        let best = board.best(difficulty, SCORES_PER_DIFFICULTY);
+        if best.is_empty() {
+            println!("    no wins yet");
+        }
+
+        for (rank, score) in best.iter().enumerate() {
+            println!("    {}. {} attempts on {}", rank + 1, score.attempts, scores::format_timestamp(score.timestamp));
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::hint::Hint;
+use crate::{Game, GuessError, Outcome};
+
+/// A multiplayer game needs at least one player to take the first turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoPlayers;
+
+impl fmt::Display for NoPlayers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a multiplayer game needs at least one player")
+    }
+}
+
+impl Error for NoPlayers {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Player {
+    pub name: String,
+    pub attempts: u32,
+    pub last_guess: Option<i32>,
+}
+
+/// Several players taking turns at the same secret number.
+#[derive(Debug, Clone)]
+pub struct Multiplayer {
+    game: Game,
+    players: Vec<Player>,
+    turn: usize,
+    winner: Option<usize>,
+    hint: Option<Hint>,
+}
+
+impl Multiplayer {
+    pub fn new(game: Game, names: Vec<String>) -> Result<Multiplayer, NoPlayers> {
+        if names.is_empty() {
+            return Err(NoPlayers);
+        }
+
+        let players = names
+            .into_iter()
+            .map(|name| Player { name, attempts: 0, last_guess: None })
+            .collect();
+
+        Ok(Multiplayer {
+            game,
+            players,
+            turn: 0,
+            winner: None,
+            hint: None,
+        })
+    }
+
+    pub fn current_player(&self) -> &Player {
+        &self.players[self.turn]
+    }
+
+    /// Invalid input does not use up the player's turn.
+    pub fn submit_input(&mut self, input: &str) -> Result<Outcome, GuessError> {
+        let outcome = self.game.submit_input(input)?;
+
+        let player = &mut self.players[self.turn];
+        player.attempts += 1;
+        let previous = std::mem::replace(&mut player.last_guess, self.game.history().last().copied());
+        self.hint = self.game.hint_since(previous);
+
+        match outcome {
+            Outcome::Won { .. } => self.winner = Some(self.turn),
+            Outcome::Wrong(_) => self.turn = (self.turn + 1) % self.players.len(),
+            Outcome::Lost { .. } => {},
+        }
+
+        Ok(outcome)
+    }
+
+    /// How close the latest guess was, the trend compares with the same player's previous guess.
+    pub fn hint(&self) -> Option<Hint> {
+        self.hint
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    pub fn winner(&self) -> Option<&Player> {
+        self.winner.map(|index| &self.players[index])
+    }
+}
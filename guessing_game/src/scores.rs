@@ -0,0 +1,102 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Difficulty;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Score {
+    pub attempts: u32,
+    pub difficulty: Difficulty,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+impl Score {
+    pub fn now(attempts: u32, difficulty: Difficulty) -> Score {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+
+        Score { attempts, difficulty, timestamp }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreBoard {
+    pub scores: Vec<Score>,
+}
+
+impl ScoreBoard {
+    /// A missing file is treated as an empty score board.
+    pub fn load(path: &Path) -> io::Result<ScoreBoard> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(ScoreBoard::default()),
+            Err(err) => return Err(err),
+        };
+
+        serde_json::from_str(&contents).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let contents = serde_json::to_string_pretty(self).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        fs::write(path, contents)
+    }
+
+    pub fn record(&mut self, score: Score) {
+        self.scores.push(score);
+    }
+
+    /// Fewest attempts first, ties go to the earlier win.
+    pub fn best(&self, difficulty: Difficulty, limit: usize) -> Vec<Score> {
+        let mut scores: Vec<Score> = self
+            .scores
+            .iter()
+            .filter(|score| score.difficulty == difficulty)
+            .copied()
+            .collect();
+
+        scores.sort_by_key(|score| (score.attempts, score.timestamp));
+        scores.truncate(limit);
+        scores
+    }
+}
+
+pub fn default_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("guessing_game").join("scores.json"))
+}
+
+/// Formats a Unix timestamp as `YYYY-MM-DD HH:MM UTC`.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let seconds_of_day = timestamp % 86_400;
+
+    // Civil-from-days, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds_of_day / 3_600,
+        seconds_of_day % 3_600 / 60
+    )
+}
@@ -0,0 +1,96 @@
+//! Protocol, one command or reply per line:
+//!
+//! ```text
+//! server: READY 1 100
+//! client: GUESS 42
+//! server: HIGHER | LOWER | WIN <attempts> | LOSE <secret> | ERROR <message>
+//! client: QUIT
+//! server: BYE
+//! ```
+//!
+//! `HIGHER` means the secret number is higher than the guess. The connection is
+//! closed after `WIN`, `LOSE` or `BYE`.
+
+use std::cmp::Ordering;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::{Difficulty, Game, Outcome};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ServerConfig {
+    pub difficulty: Difficulty,
+    pub max_attempts: Option<u32>,
+    /// Every connection gets the same secret number when set.
+    pub seed: Option<u64>,
+}
+
+/// Runs until the listener is closed. A failed `accept`, like a client resetting the
+/// connection or running out of file descriptors, only costs that one connection.
+pub fn serve(listener: TcpListener, config: ServerConfig) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("Error: could not accept a connection: {}", err);
+                continue;
+            }
+        };
+        thread::spawn(move || {
+            if let Err(err) = handle_connection(stream, config) {
+                eprintln!("Error: connection closed: {}", err);
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn handle_connection(stream: TcpStream, config: ServerConfig) -> io::Result<()> {
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let game = Game::with_rng(&mut rng, config.difficulty, config.max_attempts);
+
+    let reader = BufReader::new(stream.try_clone()?);
+    handle_client(reader, stream, game)
+}
+
+pub fn handle_client<R: BufRead, W: Write>(reader: R, mut writer: W, mut game: Game) -> io::Result<()> {
+    let range = game.difficulty().range();
+    writeln!(writer, "READY {} {}", range.start(), range.end())?;
+
+    for line in reader.lines() {
+        let line = line?;
+        let mut parts = line.split_whitespace();
+
+        let reply = match (parts.next().map(str::to_uppercase).as_deref(), parts.next(), parts.next()) {
+            (Some("GUESS"), Some(guess), None) => match game.submit_input(guess) {
+                Ok(Outcome::Wrong(Ordering::Less)) => String::from("HIGHER"),
+                Ok(Outcome::Wrong(_)) => String::from("LOWER"),
+                Ok(Outcome::Won { attempts }) => format!("WIN {}", attempts),
+                Ok(Outcome::Lost { secret_number }) => format!("LOSE {}", secret_number),
+                Err(err) => format!("ERROR {}", err),
+            },
+            (Some("QUIT"), None, None) => {
+                writeln!(writer, "BYE")?;
+                break;
+            },
+            _ => String::from("ERROR Expected GUESS <number> or QUIT"),
+        };
+
+        writeln!(writer, "{}", reply)?;
+        writer.flush()?;
+
+        if game.is_finished() {
+            break;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+
+use crate::Difficulty;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheatingError {
+    pub guess: i32,
+    pub answer: Ordering,
+}
+
+impl fmt::Display for CheatingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let answer = match self.answer {
+            Ordering::Less => "higher",
+            Ordering::Greater => "lower",
+            Ordering::Equal => "correct",
+        };
+        write!(f, "Saying {} to {} contradicts your earlier answers", answer, self.guess)
+    }
+}
+
+impl Error for CheatingError {}
+
+/// The computer's side of the game: it guesses the player's number by binary search.
+#[derive(Debug, Clone)]
+pub struct Solver {
+    low: i32,
+    high: i32,
+    guesses: u32,
+    found: Option<i32>,
+}
+
+impl Solver {
+    pub fn new(difficulty: Difficulty) -> Solver {
+        let range = difficulty.range();
+        Solver {
+            low: *range.start(),
+            high: *range.end(),
+            guesses: 0,
+            found: None,
+        }
+    }
+
+    /// Worst case number of guesses, `floor(log2(n)) + 1` for a range of `n` numbers.
+    pub fn max_guesses(difficulty: Difficulty) -> u32 {
+        let range = difficulty.range();
+        let size = (range.end() - range.start() + 1) as u32;
+        size.ilog2() + 1
+    }
+
+    /// `None` once the number has been found.
+    pub fn next_guess(&self) -> Option<i32> {
+        if self.found.is_some() {
+            return None;
+        }
+        Some(self.low + (self.high - self.low) / 2)
+    }
+
+    /// `answer` is the guess compared to the player's number, the same `Ordering`
+    /// that `Game::submit_guess` reports. Returns the number once it is found.
+    pub fn answer(&mut self, answer: Ordering) -> Result<Option<i32>, CheatingError> {
+        let guess = match self.next_guess() {
+            Some(guess) => guess,
+            None => return Ok(self.found),
+        };
+        let cheating = CheatingError { guess, answer };
+
+        match answer {
+            Ordering::Equal => self.found = Some(guess),
+            Ordering::Less => {
+                if guess >= self.high {
+                    return Err(cheating);
+                }
+                self.low = guess + 1;
+            },
+            Ordering::Greater => {
+                if guess <= self.low {
+                    return Err(cheating);
+                }
+                self.high = guess - 1;
+            },
+        }
+        self.guesses += 1;
+
+        Ok(self.found)
+    }
+
+    pub fn guesses(&self) -> u32 {
+        self.guesses
+    }
+}
+
+/// Reads the player's reply to a guess, "higher" meaning their number is higher than the guess.
+pub fn parse_answer(input: &str) -> Option<Ordering> {
+    match input.trim().to_lowercase().as_str() {
+        "h" | "higher" => Some(Ordering::Less),
+        "l" | "lower" => Some(Ordering::Greater),
+        "c" | "correct" => Some(Ordering::Equal),
+        _ => None,
+    }
+}
@@ -0,0 +1,151 @@
+use std::cmp::Ordering;
+use std::io;
+
+use crate::multiplayer::Multiplayer;
+use crate::solver::{self, Solver};
+use crate::{Difficulty, Game, Outcome};
+
+pub fn ask_input_from_user() -> Option<String> {
+    println!("Guess the number!");
+    println!("Please input your guess");
+
+    read_line()
+}
+
+fn read_line() -> Option<String> {
+    let mut guess = String::new();
+
+    let bytes_read = io::stdin()
+        .read_line(&mut guess)
+        .expect("Failed to read user input");
+
+    if bytes_read == 0 {
+        return None;
+    }
+
+    Some(guess)
+}
+
+/// Returns the final outcome, or `None` if the input ended before the game did.
+pub fn play(game: &mut Game, hints: bool) -> Option<Outcome> {
+    let range = game.difficulty().range();
+    println!("Difficulty: {}, the secret number is between {} and {}", game.difficulty(), range.start(), range.end());
+
+    while let Some(guess) = ask_input_from_user() {
+        let outcome = match game.submit_input(&guess) {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                println!("Error: {}. Please try again.", err);
+                continue;
+            }
+        };
+
+        match outcome {
+            Outcome::Won { attempts } => {
+                println!("You won in {} attempts", attempts);
+                return Some(outcome);
+            },
+            Outcome::Lost { secret_number } => {
+                println!("You lost! The secret number was {}", secret_number);
+                return Some(outcome);
+            },
+            Outcome::Wrong(Ordering::Greater) => println!("Your number is greater"),
+            Outcome::Wrong(_) => println!("Your number is smaller"),
+        }
+
+        if hints {
+            if let Some(hint) = game.hint() {
+                println!("{}", hint);
+            }
+        }
+
+        if let Some(remaining) = game.attempts_remaining() {
+            println!("Attempts left: {}", remaining);
+        }
+    }
+
+    None
+}
+
+pub fn play_multiplayer(multiplayer: &mut Multiplayer, hints: bool) {
+    let range = multiplayer.game().difficulty().range();
+    println!("Difficulty: {}, the secret number is between {} and {}", multiplayer.game().difficulty(), range.start(), range.end());
+
+    loop {
+        println!("{}, please input your guess", multiplayer.current_player().name);
+
+        let guess = match read_line() {
+            Some(guess) => guess,
+            None => break,
+        };
+
+        let outcome = match multiplayer.submit_input(&guess) {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                println!("Error: {}. Please try again.", err);
+                continue;
+            }
+        };
+
+        match outcome {
+            Outcome::Won { .. } => break,
+            Outcome::Lost { secret_number } => {
+                println!("Nobody won! The secret number was {}", secret_number);
+                break;
+            },
+            Outcome::Wrong(Ordering::Greater) => println!("Your number is greater"),
+            Outcome::Wrong(_) => println!("Your number is smaller"),
+        }
+
+        if hints {
+            if let Some(hint) = multiplayer.hint() {
+                println!("{}", hint);
+            }
+        }
+    }
+
+    if let Some(winner) = multiplayer.winner() {
+        println!("{} won in {} attempts", winner.name, winner.attempts);
+    }
+
+    for player in multiplayer.players() {
+        println!("    {}: {} attempts", player.name, player.attempts);
+    }
+}
+
+pub fn play_reverse(difficulty: Difficulty) {
+    let range = difficulty.range();
+    println!(
+        "Think of a number between {} and {}, I will find it in at most {} guesses",
+        range.start(),
+        range.end(),
+        Solver::max_guesses(difficulty)
+    );
+
+    let mut solver = Solver::new(difficulty);
+    while let Some(guess) = solver.next_guess() {
+        println!("Is it {}? (higher/lower/correct)", guess);
+
+        let answer = match read_line() {
+            Some(input) => input,
+            None => return,
+        };
+
+        let answer = match solver::parse_answer(&answer) {
+            Some(answer) => answer,
+            None => {
+                println!("Please answer higher, lower or correct");
+                continue;
+            }
+        };
+
+        match solver.answer(answer) {
+            Ok(Some(number)) => println!("Your number is {}, found in {} guesses", number, solver.guesses()),
+            Ok(None) => {},
+            Err(err) => {
+                println!("Cheater! {}", err);
+                return;
+            }
+        }
+    }
+}
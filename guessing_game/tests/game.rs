@@ -0,0 +1,86 @@
+use std::cmp::Ordering;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use guessing_game::{Difficulty, Game, GuessError, Outcome};
+
+/// Reveals the secret by losing on purpose, a one attempt game is over after any guess.
+fn secret_of(seed: u64, difficulty: Difficulty) -> i32 {
+    let mut game = Game::with_rng(&mut StdRng::seed_from_u64(seed), difficulty, Some(1));
+    game.submit_guess(*difficulty.range().start()).unwrap();
+    game.secret_number().unwrap()
+}
+
+#[test]
+fn same_seed_replays_the_same_secret_number() {
+    for difficulty in Difficulty::ALL {
+        for seed in [0, 1, 42, u64::MAX] {
+            let secret = secret_of(seed, difficulty);
+            assert_eq!(secret, secret_of(seed, difficulty));
+            assert!(difficulty.range().contains(&secret), "{} outside {:?}", secret, difficulty.range());
+        }
+    }
+}
+
+#[test]
+fn wrong_guesses_report_the_direction() {
+    let mut game = Game::new(50, Difficulty::Normal, None);
+    assert_eq!(game.submit_guess(30), Ok(Outcome::Wrong(Ordering::Less)));
+    assert_eq!(game.submit_guess(70), Ok(Outcome::Wrong(Ordering::Greater)));
+    assert_eq!(game.attempts(), 2);
+    assert_eq!(game.history(), &[30, 70]);
+    assert!(!game.is_finished());
+}
+
+#[test]
+fn correct_guess_wins_and_counts_attempts() {
+    let mut game = Game::new(50, Difficulty::Normal, Some(5));
+    game.submit_guess(10).unwrap();
+    assert_eq!(game.submit_guess(50), Ok(Outcome::Won { attempts: 2 }));
+    assert!(game.is_finished());
+}
+
+#[test]
+fn running_out_of_attempts_loses() {
+    let mut game = Game::new(50, Difficulty::Normal, Some(2));
+    assert_eq!(game.submit_guess(10), Ok(Outcome::Wrong(Ordering::Less)));
+    assert_eq!(game.attempts_remaining(), Some(1));
+    assert_eq!(game.submit_guess(20), Ok(Outcome::Lost { secret_number: 50 }));
+    assert_eq!(game.attempts_remaining(), Some(0));
+}
+
+#[test]
+fn winning_on_the_last_attempt_is_still_a_win() {
+    let mut game = Game::new(50, Difficulty::Normal, Some(1));
+    assert_eq!(game.submit_guess(50), Ok(Outcome::Won { attempts: 1 }));
+}
+
+#[test]
+fn invalid_input_does_not_use_an_attempt() {
+    let mut game = Game::new(5, Difficulty::Easy, Some(3));
+    assert_eq!(game.submit_input(""), Err(GuessError::Empty));
+    assert_eq!(game.submit_input("abc"), Err(GuessError::NotANumber(String::from("abc"))));
+    assert_eq!(game.submit_input("11"), Err(GuessError::OutOfRange { guess: 11, min: 1, max: 10 }));
+    assert_eq!(game.attempts(), 0);
+    assert_eq!(game.submit_input(" 5\n"), Ok(Outcome::Won { attempts: 1 }));
+}
+
+#[test]
+fn guessing_after_the_game_is_over_fails() {
+    let mut game = Game::new(50, Difficulty::Normal, None);
+    game.submit_guess(50).unwrap();
+    assert_eq!(game.submit_guess(50), Err(GuessError::GameOver));
+    assert_eq!(game.submit_input("50"), Err(GuessError::GameOver));
+    assert_eq!(game.attempts(), 1);
+}
+
+#[test]
+fn secret_number_is_hidden_until_the_game_is_over() {
+    let mut game = Game::new(50, Difficulty::Normal, Some(2));
+    assert_eq!(game.secret_number(), None);
+    game.submit_guess(10).unwrap();
+    assert_eq!(game.secret_number(), None);
+    game.submit_guess(20).unwrap();
+    assert_eq!(game.secret_number(), Some(50));
+}
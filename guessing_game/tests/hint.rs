@@ -0,0 +1,42 @@
+use guessing_game::hint::{Band, Hint, Trend};
+use guessing_game::Difficulty;
+
+#[test]
+fn bands_scale_with_the_difficulty() {
+    // (difficulty, furthest burning distance, furthest warm distance)
+    let limits = [(Difficulty::Normal, 4, 19), (Difficulty::Hard, 49, 199)];
+
+    for (difficulty, burning, warm) in limits {
+        assert_eq!(Band::of(0, difficulty), Band::Burning);
+        assert_eq!(Band::of(burning, difficulty), Band::Burning);
+        assert_eq!(Band::of(burning + 1, difficulty), Band::Warm);
+        assert_eq!(Band::of(warm, difficulty), Band::Warm);
+        assert_eq!(Band::of(warm + 1, difficulty), Band::Cold);
+    }
+}
+
+#[test]
+fn easy_bands_use_the_floors() {
+    // 5% and 20% of the span 9 round down to 0 and 1, the floors lift them to 1 and 2.
+    assert_eq!(Band::of(1, Difficulty::Easy), Band::Burning);
+    assert_eq!(Band::of(2, Difficulty::Easy), Band::Warm);
+    assert_eq!(Band::of(3, Difficulty::Easy), Band::Cold);
+}
+
+#[test]
+fn trend_compares_with_the_previous_guess() {
+    let hint = |guess, previous| Hint::new(50, guess, previous, Difficulty::Normal);
+
+    assert_eq!(hint(40, None).trend, None);
+    assert_eq!(hint(45, Some(40)).trend, Some(Trend::Closer));
+    assert_eq!(hint(30, Some(40)).trend, Some(Trend::Farther));
+    assert_eq!(hint(60, Some(40)).trend, Some(Trend::Same));
+    assert_eq!(hint(48, Some(40)).band, Band::Burning);
+}
+
+#[test]
+fn hints_read_as_sentences() {
+    let closer = Hint { band: Band::Warm, trend: Some(Trend::Closer) };
+    assert_eq!(closer.to_string(), "Warm, closer than your last guess");
+    assert_eq!(Hint { band: Band::Cold, trend: None }.to_string(), "Cold");
+}
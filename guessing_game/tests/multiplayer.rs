@@ -0,0 +1,83 @@
+use std::cmp::Ordering;
+
+use guessing_game::hint::Trend;
+use guessing_game::multiplayer::{Multiplayer, NoPlayers};
+use guessing_game::{Difficulty, Game, GuessError, Outcome};
+
+fn multiplayer(max_attempts: Option<u32>) -> Multiplayer {
+    let names = vec![String::from("Ada"), String::from("Bob"), String::from("Cy")];
+    Multiplayer::new(Game::new(50, Difficulty::Normal, max_attempts), names).unwrap()
+}
+
+#[test]
+fn turns_rotate_after_each_wrong_guess() {
+    let mut game = multiplayer(None);
+    assert_eq!(game.current_player().name, "Ada");
+    game.submit_input("10").unwrap();
+    assert_eq!(game.current_player().name, "Bob");
+    game.submit_input("20").unwrap();
+    assert_eq!(game.current_player().name, "Cy");
+    game.submit_input("30").unwrap();
+    assert_eq!(game.current_player().name, "Ada");
+}
+
+#[test]
+fn invalid_input_does_not_use_up_the_turn() {
+    let mut game = multiplayer(None);
+    assert_eq!(game.submit_input("abc"), Err(GuessError::NotANumber(String::from("abc"))));
+    assert_eq!(game.submit_input("500").unwrap_err(), GuessError::OutOfRange { guess: 500, min: 1, max: 100 });
+    assert_eq!(game.current_player().name, "Ada");
+    assert_eq!(game.current_player().attempts, 0);
+}
+
+#[test]
+fn the_player_who_guesses_right_wins() {
+    let mut game = multiplayer(None);
+    game.submit_input("10").unwrap();
+    assert_eq!(game.submit_input("50"), Ok(Outcome::Won { attempts: 2 }));
+
+    let winner = game.winner().unwrap();
+    assert_eq!((winner.name.as_str(), winner.attempts), ("Bob", 1));
+    assert_eq!(game.submit_input("50"), Err(GuessError::GameOver));
+}
+
+#[test]
+fn the_attempt_limit_is_shared_by_all_players() {
+    let mut game = multiplayer(Some(2));
+    assert_eq!(game.submit_input("10"), Ok(Outcome::Wrong(Ordering::Less)));
+    assert_eq!(game.submit_input("20"), Ok(Outcome::Lost { secret_number: 50 }));
+    assert!(game.winner().is_none());
+    assert_eq!(game.players().iter().map(|player| player.attempts).collect::<Vec<_>>(), [1, 1, 0]);
+}
+
+#[test]
+fn hint_trend_compares_with_the_same_players_last_guess() {
+    let mut game = multiplayer(None);
+    game.submit_input("40").unwrap();
+    assert_eq!(game.hint().unwrap().trend, None);
+
+    // Bob is farther than Ada was, but this is his first guess.
+    game.submit_input("10").unwrap();
+    assert_eq!(game.hint().unwrap().trend, None);
+
+    game.submit_input("90").unwrap();
+    // Ada moves from 40 to 30, farther than her own last guess although closer than Cy's.
+    game.submit_input("30").unwrap();
+    assert_eq!(game.hint().unwrap().trend, Some(Trend::Farther));
+    game.submit_input("20").unwrap();
+    assert_eq!(game.hint().unwrap().trend, Some(Trend::Closer));
+}
+
+#[test]
+fn a_game_without_players_is_rejected() {
+    let game = Game::new(50, Difficulty::Normal, None);
+    assert_eq!(Multiplayer::new(game, Vec::new()).unwrap_err(), NoPlayers);
+}
+
+#[test]
+fn a_single_player_keeps_the_turn() {
+    let mut game = Multiplayer::new(Game::new(50, Difficulty::Normal, None), vec![String::from("Ada")]).unwrap();
+    game.submit_input("10").unwrap();
+    assert_eq!(game.current_player().name, "Ada");
+    assert_eq!(game.current_player().attempts, 1);
+}
@@ -0,0 +1,32 @@
+use guessing_game::scores::{format_timestamp, Score, ScoreBoard};
+use guessing_game::Difficulty;
+
+fn score(attempts: u32, difficulty: Difficulty, timestamp: u64) -> Score {
+    Score { attempts, difficulty, timestamp }
+}
+
+#[test]
+fn best_orders_by_attempts_then_earliest_win() {
+    let mut board = ScoreBoard::default();
+    board.record(score(5, Difficulty::Normal, 300));
+    board.record(score(3, Difficulty::Normal, 200));
+    board.record(score(3, Difficulty::Normal, 100));
+    board.record(score(1, Difficulty::Hard, 50));
+    board.record(score(7, Difficulty::Normal, 10));
+
+    assert_eq!(
+        board.best(Difficulty::Normal, 3),
+        vec![score(3, Difficulty::Normal, 100), score(3, Difficulty::Normal, 200), score(5, Difficulty::Normal, 300)]
+    );
+    assert_eq!(board.best(Difficulty::Hard, 5), vec![score(1, Difficulty::Hard, 50)]);
+    assert!(board.best(Difficulty::Easy, 5).is_empty());
+}
+
+#[test]
+fn timestamps_format_as_utc_dates() {
+    assert_eq!(format_timestamp(0), "1970-01-01 00:00 UTC");
+    assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00 UTC");
+    assert_eq!(format_timestamp(1_234_567_890), "2009-02-13 23:31 UTC");
+    assert_eq!(format_timestamp(1_709_251_199), "2024-02-29 23:59 UTC");
+    assert_eq!(format_timestamp(4_102_444_800), "2100-01-01 00:00 UTC");
+}
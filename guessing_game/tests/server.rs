@@ -0,0 +1,63 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+use guessing_game::server::{self, ServerConfig};
+use guessing_game::Difficulty;
+
+fn start_server(config: ServerConfig) -> TcpStream {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || server::serve(listener, config));
+
+    TcpStream::connect(addr).unwrap()
+}
+
+fn send(stream: &mut TcpStream, reader: &mut impl BufRead, command: &str) -> String {
+    writeln!(stream, "{}", command).unwrap();
+    let mut reply = String::new();
+    reader.read_line(&mut reply).unwrap();
+    reply.trim_end().to_string()
+}
+
+#[test]
+fn binary_search_wins_over_tcp() {
+    let mut stream = start_server(ServerConfig { seed: Some(42), ..ServerConfig::default() });
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+    let mut greeting = String::new();
+    reader.read_line(&mut greeting).unwrap();
+    assert_eq!(greeting.trim_end(), "READY 1 100");
+
+    let (mut low, mut high) = (1, 100);
+    let mut attempts = 0;
+    loop {
+        let guess = (low + high) / 2;
+        attempts += 1;
+        match send(&mut stream, &mut reader, &format!("GUESS {}", guess)).as_str() {
+            "HIGHER" => low = guess + 1,
+            "LOWER" => high = guess - 1,
+            reply => {
+                assert_eq!(reply, format!("WIN {}", attempts));
+                break;
+            }
+        }
+    }
+    assert!(attempts <= 7);
+}
+
+#[test]
+fn bad_commands_get_errors_and_quit_says_bye() {
+    let config = ServerConfig { difficulty: Difficulty::Easy, seed: Some(1), ..ServerConfig::default() };
+    let mut stream = start_server(config);
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+    let mut greeting = String::new();
+    reader.read_line(&mut greeting).unwrap();
+    assert_eq!(greeting.trim_end(), "READY 1 10");
+
+    assert!(send(&mut stream, &mut reader, "GUESS abc").starts_with("ERROR"));
+    assert!(send(&mut stream, &mut reader, "GUESS 11").starts_with("ERROR"));
+    assert!(send(&mut stream, &mut reader, "HELLO").starts_with("ERROR"));
+    assert_eq!(send(&mut stream, &mut reader, "QUIT"), "BYE");
+}
@@ -0,0 +1,64 @@
+use std::cmp::Ordering;
+
+use guessing_game::solver::{parse_answer, CheatingError, Solver};
+use guessing_game::Difficulty;
+
+/// Plays an honest game against the solver, returns the number it settled on and how many guesses it took.
+fn solve(difficulty: Difficulty, secret: i32) -> (i32, u32) {
+    let mut solver = Solver::new(difficulty);
+    loop {
+        let guess = solver.next_guess().unwrap();
+        if let Some(found) = solver.answer(guess.cmp(&secret)).unwrap() {
+            return (found, solver.guesses());
+        }
+    }
+}
+
+#[test]
+fn max_guesses_is_the_binary_search_bound() {
+    assert_eq!(Solver::max_guesses(Difficulty::Easy), 4);
+    assert_eq!(Solver::max_guesses(Difficulty::Normal), 7);
+    assert_eq!(Solver::max_guesses(Difficulty::Hard), 10);
+}
+
+#[test]
+fn finds_every_number_within_max_guesses() {
+    for difficulty in Difficulty::ALL {
+        for secret in difficulty.range() {
+            let (found, guesses) = solve(difficulty, secret);
+            assert_eq!(found, secret);
+            assert!(guesses <= Solver::max_guesses(difficulty), "{} took {} guesses", secret, guesses);
+        }
+    }
+}
+
+#[test]
+fn lower_when_the_guess_is_already_the_lowest_is_cheating() {
+    let mut solver = Solver::new(Difficulty::Easy);
+    assert_eq!(solver.next_guess(), Some(5));
+    assert_eq!(solver.answer(Ordering::Less), Ok(None));
+    assert_eq!(solver.next_guess(), Some(8));
+    assert_eq!(solver.answer(Ordering::Less), Ok(None));
+    assert_eq!(solver.next_guess(), Some(9));
+
+    assert_eq!(solver.answer(Ordering::Greater), Err(CheatingError { guess: 9, answer: Ordering::Greater }));
+}
+
+#[test]
+fn higher_when_the_guess_is_already_the_highest_is_cheating() {
+    let mut solver = Solver::new(Difficulty::Easy);
+    for _ in 0..3 {
+        solver.answer(Ordering::Less).unwrap();
+    }
+    assert_eq!(solver.next_guess(), Some(10));
+
+    assert_eq!(solver.answer(Ordering::Less), Err(CheatingError { guess: 10, answer: Ordering::Less }));
+}
+
+#[test]
+fn answers_are_parsed_from_the_players_point_of_view() {
+    assert_eq!(parse_answer("higher"), Some(Ordering::Less));
+    assert_eq!(parse_answer(" L\n"), Some(Ordering::Greater));
+    assert_eq!(parse_answer("correct"), Some(Ordering::Equal));
+    assert_eq!(parse_answer("maybe"), None);
+}